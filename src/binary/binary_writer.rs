@@ -0,0 +1,462 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Provides a binary Ion writer that accepts symbol text and manages local symbol tables on
+//! behalf of the caller.
+
+use std::io::Write;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset};
+use delegate::delegate;
+use num_bigint::BigInt;
+
+use crate::binary::writer::BinarySystemWriter;
+use crate::catalog::{Catalog, SharedSymbolTable};
+use crate::constants::v1_0::system_symbol_ids;
+use crate::cursor::RawSymbolToken;
+use crate::result::{illegal_operation, IonResult};
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
+use crate::types::SymbolId;
+use crate::writer::IonWriter;
+use crate::{IonType, SymbolTable};
+
+/// A streaming binary Ion writer that takes field names, annotations and symbol values as text.
+///
+/// Each new piece of symbol text is assigned a symbol ID in the writer's local symbol table.
+/// Values are buffered until [`flush`](Self::flush) is called; at that point, a local symbol
+/// table declaring any new symbols is written to the output ahead of the buffered values. Symbol
+/// tables after the first are appended to their predecessor so previously assigned IDs remain
+/// valid.
+pub struct BinaryWriter<W: Write> {
+    // Writes the IVM and local symbol tables directly to the output sink.
+    system_writer: BinarySystemWriter<W>,
+    // Encodes values into a buffer. Values may refer to symbols that have not been declared yet,
+    // so they can only be written out after the local symbol table that declares them.
+    value_writer: BinarySystemWriter<Vec<u8>>,
+    symbol_table: SymbolTable,
+    imports: Vec<SharedSymbolTable>,
+    // The lowest symbol ID that has not yet been declared in the output stream.
+    first_pending_sid: SymbolId,
+    // Whether a local symbol table has already been written to the output stream.
+    symbol_table_written: bool,
+}
+
+impl<W: Write> BinaryWriter<W> {
+    /// Creates a new BinaryWriter that will write its encoded output to the provided io::Write
+    /// sink.
+    pub fn new(out: W) -> BinaryWriter<W> {
+        let symbol_table = SymbolTable::new();
+        BinaryWriter {
+            system_writer: BinarySystemWriter::new(out),
+            value_writer: BinarySystemWriter::new_without_ivm(Vec::new()),
+            first_pending_sid: symbol_table.len(),
+            symbol_table,
+            imports: Vec::new(),
+            symbol_table_written: false,
+        }
+    }
+
+    /// Creates a new BinaryWriter whose local symbol tables import the specified
+    /// `(name, version)` shared symbol tables from `catalog`. Symbol text defined by an imported
+    /// table will be written using that table's symbol ID. If any of the tables is not in the
+    /// catalog, returns Err.
+    pub fn with_imports(
+        out: W,
+        catalog: &dyn Catalog,
+        imports: &[(&str, usize)],
+    ) -> IonResult<BinaryWriter<W>> {
+        let mut writer = BinaryWriter::new(out);
+        for (name, version) in imports {
+            let table = match catalog.get_table_with_version(name, *version) {
+                Some(table) => table,
+                None => {
+                    return illegal_operation(format!(
+                        "The catalog does not contain version {} of shared symbol table '{}'.",
+                        version, name
+                    ))
+                }
+            };
+            for symbol in table.symbols() {
+                match symbol {
+                    Some(text) => writer.symbol_table.add_symbol(text.to_string()),
+                    None => writer.symbol_table.add_placeholder(),
+                };
+            }
+            writer.imports.push(table.clone());
+        }
+        writer.first_pending_sid = writer.symbol_table.len();
+        Ok(writer)
+    }
+
+    /// Returns the symbol table that the writer is using to assign symbol IDs.
+    pub fn symbol_table(&self) -> &SymbolTable {
+        &self.symbol_table
+    }
+
+    // Returns the symbol ID for the provided text, assigning a new one if necessary.
+    fn sid_for(&mut self, text: &str) -> SymbolId {
+        match self.symbol_table.sid_for(&text) {
+            Some(sid) => sid,
+            None => self.symbol_table.intern(text.to_string()),
+        }
+    }
+
+    // Returns the symbol ID for the provided token. Symbol IDs must already be defined in the
+    // writer's symbol table.
+    fn sid_for_token(&mut self, token: &RawSymbolToken) -> IonResult<SymbolId> {
+        match token {
+            RawSymbolToken::Text(text) => Ok(self.sid_for(text)),
+            RawSymbolToken::SymbolId(sid) if self.symbol_table.sid_is_valid(*sid) => Ok(*sid),
+            RawSymbolToken::SymbolId(sid) => illegal_operation(format!(
+                "Symbol ID ${} is not defined in the writer's symbol table.",
+                sid
+            )),
+        }
+    }
+
+    /// Sets the field name for the next value written. This method should only be called while
+    /// the writer is positioned within a struct.
+    pub fn set_field_name(&mut self, name: &str) {
+        let field_id = self.sid_for(name);
+        self.value_writer.set_field_id(field_id);
+    }
+
+    /// Sets the annotations for the next value written.
+    pub fn set_annotations(&mut self, annotations: &[&str]) {
+        let annotation_ids: Vec<SymbolId> = annotations
+            .iter()
+            .map(|annotation| self.sid_for(annotation))
+            .collect();
+        self.value_writer.set_annotation_ids(&annotation_ids);
+    }
+
+    /// Writes a symbol value with the provided text.
+    pub fn write_symbol<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        let symbol_id = self.sid_for(value.as_ref());
+        self.value_writer.write_symbol_id(symbol_id)
+    }
+
+    delegate! {
+        to self.value_writer {
+            pub fn write_null(&mut self, ion_type: IonType) -> IonResult<()>;
+            pub fn write_bool(&mut self, value: bool) -> IonResult<()>;
+            pub fn write_i64(&mut self, value: i64) -> IonResult<()>;
+            pub fn write_big_int(&mut self, value: &BigInt) -> IonResult<()>;
+            pub fn write_f32(&mut self, value: f32) -> IonResult<()>;
+            pub fn write_f64(&mut self, value: f64) -> IonResult<()>;
+            pub fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()>;
+            pub fn write_datetime(&mut self, value: &DateTime<FixedOffset>) -> IonResult<()>;
+            pub fn write_decimal(&mut self, value: &Decimal) -> IonResult<()>;
+            pub fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()>;
+            pub fn write_string<S: AsRef<str>>(&mut self, value: S) -> IonResult<()>;
+            pub fn write_clob(&mut self, value: &[u8]) -> IonResult<()>;
+            pub fn write_blob(&mut self, value: &[u8]) -> IonResult<()>;
+            pub fn step_in(&mut self, ion_type: IonType) -> IonResult<()>;
+            pub fn step_out(&mut self) -> IonResult<()>;
+        }
+    }
+
+    /// Writes a local symbol table declaring any new symbols, followed by all of the values
+    /// written since the last call to `flush`. This method can only be called when the writer is
+    /// at the top level.
+    pub fn flush(&mut self) -> IonResult<()> {
+        self.value_writer.flush()?;
+
+        let has_new_symbols = self.symbol_table.len() > self.first_pending_sid;
+        let has_pending_imports = !self.symbol_table_written && !self.imports.is_empty();
+        if has_new_symbols || has_pending_imports {
+            self.write_symbol_table()?;
+        }
+        self.system_writer.flush()?;
+
+        let encoded_values = self.value_writer.output_mut();
+        self.system_writer.output_mut().write_all(encoded_values)?;
+        encoded_values.clear();
+        Ok(())
+    }
+
+    // Writes a local symbol table declaring the imports (if this is the first table) and any
+    // symbols that have not been declared yet.
+    fn write_symbol_table(&mut self) -> IonResult<()> {
+        let writer = &mut self.system_writer;
+        writer.set_annotation_ids(&[system_symbol_ids::ION_SYMBOL_TABLE]);
+        writer.step_in(IonType::Struct)?;
+
+        if self.symbol_table_written {
+            // Keep the symbols declared by the previous local symbol table.
+            writer.set_field_id(system_symbol_ids::IMPORTS);
+            writer.write_symbol_id(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        } else if !self.imports.is_empty() {
+            writer.set_field_id(system_symbol_ids::IMPORTS);
+            writer.step_in(IonType::List)?;
+            for import in &self.imports {
+                writer.step_in(IonType::Struct)?;
+                writer.set_field_id(system_symbol_ids::NAME);
+                writer.write_string(import.name())?;
+                writer.set_field_id(system_symbol_ids::VERSION);
+                writer.write_i64(import.version() as i64)?;
+                writer.set_field_id(system_symbol_ids::MAX_ID);
+                writer.write_i64(import.symbols().len() as i64)?;
+                writer.step_out()?;
+            }
+            writer.step_out()?;
+        }
+
+        let new_symbols = self.symbol_table.symbols_tail(self.first_pending_sid);
+        if !new_symbols.is_empty() {
+            writer.set_field_id(system_symbol_ids::SYMBOLS);
+            writer.step_in(IonType::List)?;
+            for text in new_symbols {
+                // Symbols are only ever added to the local table by interning text.
+                writer.write_string(try_to!(text))?;
+            }
+            writer.step_out()?;
+        }
+
+        writer.step_out()?;
+        self.symbol_table_written = true;
+        self.first_pending_sid = self.symbol_table.len();
+        Ok(())
+    }
+
+    /// Returns a reference to the underlying io::Write implementation.
+    pub fn output(&self) -> &W {
+        self.system_writer.output()
+    }
+
+    /// Returns a mutable reference to the underlying io::Write implementation. Modifying the
+    /// underlying sink is an inherently risky operation and can result in unexpected behavior.
+    /// It is not recommended for most use cases.
+    pub fn output_mut(&mut self) -> &mut W {
+        self.system_writer.output_mut()
+    }
+
+    /// Consumes the writer and returns the underlying io::Write implementation. Any data that has
+    /// not been flushed is discarded.
+    pub fn into_output(self) -> W {
+        self.system_writer.into_output()
+    }
+}
+
+impl<W: Write> IonWriter for BinaryWriter<W> {
+    fn set_annotations(&mut self, annotations: &[RawSymbolToken]) -> IonResult<()> {
+        let annotation_ids = annotations
+            .iter()
+            .map(|annotation| self.sid_for_token(annotation))
+            .collect::<IonResult<Vec<SymbolId>>>()?;
+        self.value_writer.set_annotation_ids(&annotation_ids);
+        Ok(())
+    }
+
+    fn set_field_name(&mut self, name: RawSymbolToken) -> IonResult<()> {
+        let field_id = self.sid_for_token(&name)?;
+        self.value_writer.set_field_id(field_id);
+        Ok(())
+    }
+
+    fn write_symbol(&mut self, value: RawSymbolToken) -> IonResult<()> {
+        let symbol_id = self.sid_for_token(&value)?;
+        self.value_writer.write_symbol_id(symbol_id)
+    }
+
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        BinaryWriter::write_null(self, ion_type)
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        BinaryWriter::write_bool(self, value)
+    }
+
+    fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        BinaryWriter::write_i64(self, value)
+    }
+
+    fn write_big_int(&mut self, value: &BigInt) -> IonResult<()> {
+        BinaryWriter::write_big_int(self, value)
+    }
+
+    fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        BinaryWriter::write_f32(self, value)
+    }
+
+    fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        BinaryWriter::write_f64(self, value)
+    }
+
+    fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()> {
+        BinaryWriter::write_big_decimal(self, value)
+    }
+
+    fn write_datetime(&mut self, value: &DateTime<FixedOffset>) -> IonResult<()> {
+        BinaryWriter::write_datetime(self, value)
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        BinaryWriter::write_decimal(self, value)
+    }
+
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        BinaryWriter::write_timestamp(self, value)
+    }
+
+    fn write_string(&mut self, value: &str) -> IonResult<()> {
+        BinaryWriter::write_string(self, value)
+    }
+
+    fn write_clob(&mut self, value: &[u8]) -> IonResult<()> {
+        BinaryWriter::write_clob(self, value)
+    }
+
+    fn write_blob(&mut self, value: &[u8]) -> IonResult<()> {
+        BinaryWriter::write_blob(self, value)
+    }
+
+    fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
+        BinaryWriter::step_in(self, ion_type)
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        BinaryWriter::step_out(self)
+    }
+
+    fn flush(&mut self) -> IonResult<()> {
+        BinaryWriter::flush(self)
+    }
+}
+
+#[cfg(test)]
+mod binary_writer_tests {
+    use super::*;
+    use crate::binary::constants::v1_0::IVM;
+    use crate::catalog::MapCatalog;
+    use crate::{BinaryIonCursor, Reader};
+    use std::io;
+
+    fn catalog() -> MapCatalog {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(SharedSymbolTable::new(
+            "shared",
+            1,
+            vec![Some("a".to_string()), None, Some("b".to_string())],
+        ));
+        catalog
+    }
+
+    fn reader_for(data: &[u8]) -> Reader<BinaryIonCursor<io::Cursor<&[u8]>>> {
+        let mut reader = Reader::new(BinaryIonCursor::new(io::Cursor::new(data)));
+        reader.set_catalog(catalog());
+        reader
+    }
+
+    #[test]
+    fn write_symbols_as_text() -> IonResult<()> {
+        let mut writer = BinaryWriter::new(vec![]);
+        // foo::{bar: baz, quux: [foo, bar]}
+        writer.set_annotations(&["foo"]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("bar");
+        writer.write_symbol("baz")?;
+        writer.set_field_name("quux");
+        writer.step_in(IonType::List)?;
+        writer.write_symbol("foo")?;
+        writer.write_symbol("bar")?;
+        writer.step_out()?;
+        writer.step_out()?;
+        writer.flush()?;
+        let output = writer.into_output();
+
+        let mut reader = reader_for(&output);
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        assert_eq!(
+            reader.annotations().collect::<IonResult<Vec<&str>>>()?,
+            vec!["foo"]
+        );
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.field_name(), Some("bar"));
+        assert_eq!(reader.read_symbol()?.unwrap().as_str(), "baz");
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        assert_eq!(reader.field_name(), Some("quux"));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.read_symbol_id()?, Some(10));
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.read_symbol_id()?, Some(11));
+        reader.step_out()?;
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn append_symbol_tables() -> IonResult<()> {
+        let mut writer = BinaryWriter::new(vec![]);
+        writer.write_symbol("foo")?;
+        writer.flush()?;
+        writer.write_symbol("foo")?;
+        writer.write_symbol("bar")?;
+        writer.flush()?;
+        // Nothing new was written, so this should have no effect.
+        writer.flush()?;
+
+        let mut expected = IVM.to_vec();
+        expected.extend_from_slice(&[
+            0xE9, 0x81, 0x83, 0xD6, 0x87, 0xB4, 0x83, b'f', b'o', b'o', // {symbols: ["foo"]}
+            0x71, 0x0A, // $10
+            0xEC, 0x81, 0x83, 0xD9, 0x86, 0x71, 0x03, 0x87, 0xB4, 0x83, b'b', b'a',
+            b'r', // {imports: $ion_symbol_table, symbols: ["bar"]}
+            0x71, 0x0A, // $10
+            0x71, 0x0B, // $11
+        ]);
+        assert_eq!(expected, writer.into_output());
+        Ok(())
+    }
+
+    #[test]
+    fn write_with_imports() -> IonResult<()> {
+        let mut writer = BinaryWriter::with_imports(vec![], &catalog(), &[("shared", 1)])?;
+        writer.write_symbol("b")?;
+        writer.write_symbol("c")?;
+        writer.write_symbol("a")?;
+        writer.flush()?;
+        let output = writer.into_output();
+
+        let mut reader = reader_for(&output);
+        for (sid, text) in &[(12, "b"), (13, "c"), (10, "a")] {
+            assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+            assert_eq!(reader.read_symbol_id()?, Some(*sid));
+            assert_eq!(reader.read_symbol()?.unwrap().as_str(), *text);
+        }
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn write_with_imports_and_no_symbols() -> IonResult<()> {
+        let mut writer = BinaryWriter::with_imports(vec![], &catalog(), &[("shared", 1)])?;
+        writer.write_i64(5)?;
+        writer.flush()?;
+        let output = writer.into_output();
+
+        let mut reader = reader_for(&output);
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.symbol_table().text_for(12), Some("b"));
+        Ok(())
+    }
+
+    #[test]
+    fn import_not_in_catalog() {
+        let result = BinaryWriter::with_imports(vec![], &catalog(), &[("shared", 2)]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn flush_in_container_fails() -> IonResult<()> {
+        let mut writer = BinaryWriter::new(vec![]);
+        writer.step_in(IonType::List)?;
+        writer.write_symbol("foo")?;
+        assert!(writer.flush().is_err());
+        Ok(())
+    }
+}
@@ -4,9 +4,10 @@ use bytes::ByteOrder;
 use chrono::offset::FixedOffset;
 use chrono::prelude::*;
 use delegate::delegate;
+use num_bigint::{BigInt, BigUint, Sign};
 
 use crate::binary::constants::v1_0::IVM;
-use crate::cursor::{Cursor, StreamItem};
+use crate::cursor::{Cursor, IntegerSize, StreamItem};
 use crate::{
     binary::{
         constants::v1_0::length_codes,
@@ -18,10 +19,20 @@ use crate::{
         IonTypeCode,
     },
     data_source::IonDataSource,
-    result::{decoding_error, illegal_operation, illegal_operation_raw, IonResult},
-    types::{IonType, SymbolId},
+    result::{
+        decoding_error, decoding_error_raw, illegal_operation, illegal_operation_raw, IonResult,
+    },
+    types::{
+        coefficient::{self, Coefficient},
+        decimal::Decimal,
+        magnitude::Magnitude,
+        timestamp::{Mantissa, Precision, Timestamp},
+        IonType, SymbolId,
+    },
 };
+use std::convert::TryFrom;
 use std::io;
+use std::mem;
 
 use std::ops::Range;
 
@@ -249,6 +260,11 @@ pub struct CursorState {
     // Having a single, reusable Vec reduces allocations and keeps the size of
     // the EncodedValue type (which is frequently moved) small.
     annotations: Vec<SymbolId>,
+    // The magnitude of the current value if it is an integer that `next()` read eagerly.
+    // Integers whose encodings are at least as long as an i64 may or may not fit in one
+    // depending on their leading zero bytes, so their magnitude must be read to answer
+    // `integer_size()`.
+    int_magnitude: Option<Magnitude>,
 }
 
 /// Verifies that the current value is of the expected type and that the bytes representing that
@@ -284,6 +300,7 @@ impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
     fn next(&mut self) -> IonResult<Option<StreamItem>> {
         // Skip the remaining bytes of the current value, if any.
         let _ = self.skip_current_value()?;
+        self.cursor.int_magnitude = None;
 
         if let Some(ref parent) = self.cursor.parents.last() {
             // If the cursor is nested inside a parent object, don't attempt to read beyond the end of
@@ -312,10 +329,9 @@ impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
         if header.ion_type_code == IonTypeCode::Annotation {
             if header.length_code == 0 {
                 // This is actually the first byte in an Ion Version Marker
-                // TODO: actually parse the IVM instead of assuming 1.0 and skipping it
-                self.cursor.ion_version = (1, 0);
-                self.skip_bytes(IVM.len() - 1)?;
-                return Ok(Some(StreamItem::VersionMarker(1, 0)));
+                let (major, minor) = self.read_ivm()?;
+                self.cursor.ion_version = (major, minor);
+                return Ok(Some(StreamItem::VersionMarker(major, minor)));
             }
             // We've found an annotated value. Read all of the annotation symbols leading
             // up to the value.
@@ -330,6 +346,13 @@ impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
 
         let _ = self.process_header_by_type_code(header)?;
 
+        if self.cursor.value.ion_type == IonType::Integer
+            && !self.cursor.value.is_null
+            && self.cursor.value.value_length >= mem::size_of::<i64>()
+        {
+            self.cursor.int_magnitude = Some(self.read_value_as_magnitude()?);
+        }
+
         self.cursor.index_at_depth += 1;
         self.cursor.value.index_at_depth = self.cursor.index_at_depth;
 
@@ -384,22 +407,46 @@ impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
         }
     }
 
-    // TODO:
-    // - Detect overflow and return an Error
-    // - Add an integer_size() method that indicates whether the current value will fit in an i64
+    fn integer_size(&self) -> Option<IntegerSize> {
+        if self.ion_type() != Some(IonType::Integer) || self.is_null() {
+            return None;
+        }
+        // Integers that are shorter than an i64 always fit in one. Longer ones were read by
+        // `next()`, so their actual value can be checked.
+        match &self.cursor.int_magnitude {
+            Some(magnitude) if self.int_magnitude_as_i64(magnitude).is_none() => {
+                Some(IntegerSize::BigInt)
+            }
+            _ => Some(IntegerSize::I64),
+        }
+    }
+
     fn read_i64(&mut self) -> IonResult<Option<i64>> {
-        read_safety_checks!(self, IonType::Integer);
+        let magnitude = match self.read_integer_magnitude()? {
+            Some(magnitude) => magnitude,
+            None => return Ok(None),
+        };
+        match self.int_magnitude_as_i64(&magnitude) {
+            Some(value) => Ok(Some(value)),
+            None => decoding_error("Found an integer that is too large to fit in an i64."),
+        }
+    }
 
-        let magnitude = self.read_value_as_uint()?.value();
+    fn read_big_int(&mut self) -> IonResult<Option<BigInt>> {
+        let magnitude = match self.read_integer_magnitude()? {
+            Some(Magnitude::U64(magnitude)) => BigUint::from(magnitude),
+            Some(Magnitude::BigUInt(magnitude)) => magnitude,
+            None => return Ok(None),
+        };
 
         use self::IonTypeCode::*;
-        let value = match self.cursor.value.header.ion_type_code {
-            PositiveInteger => magnitude as i64,
-            NegativeInteger => -(magnitude as i64),
+        let sign = match self.cursor.value.header.ion_type_code {
+            PositiveInteger => Sign::Plus,
+            NegativeInteger => Sign::Minus,
             itc @ _ => unreachable!("Unexpected IonTypeCode: {:?}", itc),
         };
 
-        Ok(Some(value))
+        Ok(Some(BigInt::from_biguint(sign, magnitude)))
     }
 
     fn read_f32(&mut self) -> IonResult<Option<f32>> {
@@ -450,6 +497,23 @@ impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
         Ok(Some(BigDecimal::new(coefficient.into(), -exponent)))
     }
 
+    fn read_decimal(&mut self) -> IonResult<Option<Decimal>> {
+        read_safety_checks!(self, IonType::Decimal);
+
+        if self.cursor.value.value_length == 0 {
+            return Ok(Some(Decimal::new(0, 0)));
+        }
+
+        let exponent_var_int = self.read_var_int()?;
+        let coefficient_size_in_bytes =
+            self.cursor.value.value_length - exponent_var_int.size_in_bytes();
+
+        let exponent = exponent_var_int.value() as i64;
+        let coefficient = self.read_coefficient(coefficient_size_in_bytes)?;
+
+        Ok(Some(Decimal::new(coefficient, exponent)))
+    }
+
     fn read_string(&mut self) -> IonResult<Option<String>> {
         self.string_ref_map(|s: &str| s.into())
     }
@@ -607,6 +671,155 @@ impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
         Ok(Some(datetime))
     }
 
+    fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>> {
+        read_safety_checks!(self, IonType::Timestamp);
+
+        let timestamp_start_offset = self.cursor.bytes_read;
+
+        let offset = self.read_var_int()?;
+        let year = self.read_var_uint()?.value() as i32;
+
+        let mut precision = Precision::Year;
+        let mut month = 1;
+        let mut day = 1;
+        let mut hour = 0;
+        let mut minute = 0;
+        let mut second = 0;
+        let mut nanoseconds = 0;
+        let mut fractional_seconds = None;
+
+        loop {
+            if self.finished_reading_value() {
+                break;
+            }
+
+            month = self.read_var_uint()?.value() as u32;
+            precision = Precision::Month;
+            if self.finished_reading_value() {
+                break;
+            }
+
+            day = self.read_var_uint()?.value() as u32;
+            precision = Precision::Day;
+            if self.finished_reading_value() {
+                break;
+            }
+
+            hour = self.read_var_uint()?.value() as u32;
+            if self.finished_reading_value() {
+                return decoding_error("Found a timestamp with an hour but no minute.");
+            }
+            minute = self.read_var_uint()?.value() as u32;
+            precision = Precision::HourAndMinute;
+            if self.finished_reading_value() {
+                break;
+            }
+
+            second = self.read_var_uint()?.value() as u32;
+            precision = Precision::Second;
+            if self.finished_reading_value() {
+                break;
+            }
+
+            let exponent = self.read_var_int()?.value();
+            // The remaining bytes represent the coefficient. We need to determine how many bytes
+            // we've read to know how many remain.
+            let value_bytes_read = self.cursor.bytes_read - timestamp_start_offset;
+            let coefficient_size_in_bytes = self.cursor.value.value_length - value_bytes_read;
+            let coefficient = self.read_coefficient(coefficient_size_in_bytes)?;
+
+            // A fractional seconds value of zero with a non-negative exponent (e.g. `0d0`) does
+            // not add any precision to the timestamp.
+            let is_zero = *coefficient.magnitude() == Magnitude::U64(0);
+            if exponent >= 0 && is_zero {
+                break;
+            }
+            // The fractional seconds must be in the range [0, 1), which means that the
+            // coefficient cannot have more digits than there are places after the decimal point.
+            let magnitude_digits = match coefficient.magnitude() {
+                Magnitude::U64(magnitude) => magnitude.to_string().len(),
+                Magnitude::BigUInt(magnitude) => magnitude.to_str_radix(10).len(),
+            };
+            let out_of_range = || {
+                decoding_error_raw(format!(
+                    "Found a timestamp with fractional seconds outside the range [0, 1): {:?}e{}",
+                    coefficient, exponent
+                ))
+            };
+            if exponent >= 0 || (coefficient.sign() == coefficient::Sign::Negative && !is_zero) {
+                return Err(out_of_range());
+            }
+            let number_of_digits = match exponent.checked_neg().map(u32::try_from) {
+                Some(Ok(number_of_digits)) => number_of_digits,
+                _ => {
+                    return decoding_error(format!(
+                        "Found a timestamp with too many digits of fractional seconds: {}",
+                        exponent
+                    ))
+                }
+            };
+            if magnitude_digits as u64 > number_of_digits as u64 {
+                return Err(out_of_range());
+            }
+            let fraction = Decimal::new(
+                Coefficient::new(coefficient::Sign::Positive, coefficient.magnitude().clone()),
+                exponent,
+            );
+            precision = Precision::FractionalSeconds;
+            fractional_seconds = match coefficient.magnitude() {
+                // Precisions up to nanoseconds are stored in the NaiveDateTime.
+                Magnitude::U64(magnitude) if number_of_digits <= 9 => {
+                    nanoseconds = *magnitude as u32 * 10u32.pow(9 - number_of_digits);
+                    Some(Mantissa::Digits(number_of_digits))
+                }
+                _ => Some(Mantissa::Arbitrary(fraction)),
+            };
+            break;
+        }
+
+        // Binary timestamps store their fields in UTC, which is also how Timestamp stores them.
+        let date_time = NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_nano_opt(hour, minute, second, nanoseconds))
+            .ok_or_else(|| {
+                decoding_error_raw(format!(
+                    "{}: year={}, month={}, day={}, hour={}, minute={}, second={}, nanos={}",
+                    "Read a timestamp that would not be a legal DateTime.",
+                    year,
+                    month,
+                    day,
+                    hour,
+                    minute,
+                    second,
+                    nanoseconds
+                ))
+            })?;
+
+        // An offset of -0 indicates that the offset is unknown. Timestamps without a time
+        // component always have an unknown offset.
+        let offset = if offset.is_negative_zero() || precision < Precision::HourAndMinute {
+            None
+        } else {
+            let offset = i32::try_from(offset.value())
+                .ok()
+                .and_then(|minutes| minutes.checked_mul(60))
+                .and_then(FixedOffset::east_opt)
+                .ok_or_else(|| {
+                    decoding_error_raw(format!(
+                        "Found a timestamp with an invalid offset: {} minutes",
+                        offset.value()
+                    ))
+                })?;
+            Some(offset)
+        };
+
+        Ok(Some(Timestamp {
+            date_time,
+            offset,
+            precision,
+            fractional_seconds,
+        }))
+    }
+
     #[inline]
     fn step_in(&mut self) -> IonResult<()> {
         use self::IonType::*;
@@ -794,6 +1007,7 @@ where
                 value: Default::default(),
                 parents: Vec::new(),
                 annotations: Vec::new(),
+                int_magnitude: None,
             },
             header_cache: create_header_byte_jump_table(),
         }
@@ -808,6 +1022,57 @@ where
             && self.cursor.bytes_read >= self.cursor.value.value_end_exclusive()
     }
 
+    // Returns the magnitude of the current integer, reading it from the data source unless
+    // `next()` already did.
+    fn read_integer_magnitude(&mut self) -> IonResult<Option<Magnitude>> {
+        if self.cursor.value.ion_type != IonType::Integer || self.cursor.value.is_null {
+            return Ok(None);
+        }
+        if let Some(magnitude) = &self.cursor.int_magnitude {
+            return Ok(Some(magnitude.clone()));
+        }
+        read_safety_checks!(self, IonType::Integer);
+        Ok(Some(self.read_value_as_magnitude()?))
+    }
+
+    // Reads the bytes of the current value as the magnitude of an integer. The encoding may be
+    // padded with leading zero bytes, which are skipped when deciding whether the magnitude
+    // fits in a u64.
+    fn read_value_as_magnitude(&mut self) -> IonResult<Magnitude> {
+        let number_of_bytes = self.cursor.value.value_length;
+        self.read_slice(number_of_bytes, |buffer: &[u8]| {
+            let leading_zeros = buffer.iter().take_while(|byte| **byte == 0).count();
+            let buffer = &buffer[leading_zeros..];
+            let magnitude = if buffer.len() <= mem::size_of::<u64>() {
+                let value = buffer
+                    .iter()
+                    .fold(0u64, |value, byte| (value << 8) | *byte as u64);
+                Magnitude::U64(value)
+            } else {
+                Magnitude::BigUInt(BigUint::from_bytes_be(buffer))
+            };
+            Ok(magnitude)
+        })
+    }
+
+    // Applies the current integer's sign to the provided magnitude, returning None if the
+    // result would not fit in an i64.
+    fn int_magnitude_as_i64(&self, magnitude: &Magnitude) -> Option<i64> {
+        let magnitude = match magnitude {
+            Magnitude::U64(magnitude) => *magnitude,
+            Magnitude::BigUInt(_) => return None,
+        };
+        use self::IonTypeCode::*;
+        match self.cursor.value.header.ion_type_code {
+            PositiveInteger if magnitude <= i64::MAX as u64 => Some(magnitude as i64),
+            // The magnitude of i64::MIN is one larger than i64::MAX.
+            NegativeInteger if magnitude <= i64::MAX as u64 + 1 => {
+                Some((magnitude as i64).wrapping_neg())
+            }
+            _ => None,
+        }
+    }
+
     fn clear_annotations(&mut self) {
         if self.cursor.value.number_of_annotations > 0 {
             // Drop the annotations belonging to the last value read from the annotations Vec
@@ -855,6 +1120,35 @@ where
         Ok(int)
     }
 
+    // Reads an Int of any size as the coefficient of a decimal. Unlike Int::read, this preserves
+    // negative zero.
+    fn read_coefficient(&mut self, number_of_bytes: usize) -> IonResult<Coefficient> {
+        if number_of_bytes == 0 {
+            return Ok(Coefficient::new(coefficient::Sign::Positive, 0u64));
+        }
+        self.read_slice(number_of_bytes, |buffer: &[u8]| {
+            let sign = if buffer[0] & 0b1000_0000 == 0 {
+                coefficient::Sign::Positive
+            } else {
+                coefficient::Sign::Negative
+            };
+            let first_byte = buffer[0] & 0b0111_1111;
+            let magnitude = if buffer.len() <= mem::size_of::<u64>() {
+                let magnitude = buffer[1..]
+                    .iter()
+                    .fold(first_byte as u64, |magnitude, byte| {
+                        (magnitude << 8) | *byte as u64
+                    });
+                Magnitude::U64(magnitude)
+            } else {
+                let mut magnitude_bytes = buffer.to_vec();
+                magnitude_bytes[0] = first_byte;
+                Magnitude::BigUInt(BigUint::from_bytes_be(&magnitude_bytes))
+            };
+            Ok(Coefficient::new(sign, magnitude))
+        })
+    }
+
     fn process_header_by_type_code(&mut self, header: Header) -> IonResult<()> {
         self.cursor.value.ion_type = header.ion_type.unwrap(); // TODO: Is cursor.value.ion_type redundant?
         self.cursor.value.header = header;
@@ -934,6 +1228,35 @@ where
         byte
     }
 
+    // Reads the remainder of an Ion Version Marker whose first byte (0xE0) has already been read
+    // as a value header, returning the (major, minor) version it specifies.
+    fn read_ivm(&mut self) -> IonResult<(u8, u8)> {
+        if !self.cursor.parents.is_empty() {
+            return decoding_error("Found an Ion version marker inside a container.");
+        }
+        let mut bytes = [0u8; 3];
+        for byte in bytes.iter_mut() {
+            *byte = match self.next_byte()? {
+                Some(byte) => byte,
+                None => return decoding_error("Found an incomplete Ion version marker."),
+            };
+        }
+        let [major, minor, end] = bytes;
+        if end != IVM[3] {
+            return decoding_error(format!(
+                "Found a malformed Ion version marker: E0 {:02X} {:02X} {:02X}",
+                major, minor, end
+            ));
+        }
+        if (major, minor) != (1, 0) {
+            return decoding_error(format!(
+                "Found an Ion version marker for unsupported Ion version {}.{}",
+                major, minor
+            ));
+        }
+        Ok((major, minor))
+    }
+
     fn skip_bytes(&mut self, number_of_bytes: usize) -> IonResult<()> {
         if number_of_bytes == 0 {
             return Ok(());
@@ -1022,9 +1345,15 @@ mod tests {
 
     use crate::binary::constants::v1_0::IVM;
     use crate::binary::cursor::BinaryIonCursor;
-    use crate::cursor::{Cursor, StreamItem, StreamItem::*};
+    use crate::cursor::{Cursor, IntegerSize, StreamItem, StreamItem::*};
     use crate::result::IonResult;
+    use crate::types::coefficient::{Coefficient, Sign};
+    use crate::types::decimal::Decimal;
+    use crate::types::timestamp::Timestamp;
     use crate::types::IonType;
+    use crate::value::AnyInt;
+    use num_bigint::{BigInt, BigUint};
+    use rstest::*;
     use std::convert::TryInto;
 
     type TestDataSource = io::Cursor<Vec<u8>>;
@@ -1052,6 +1381,44 @@ mod tests {
         binary_cursor
     }
 
+    #[test]
+    fn test_read_ivm_mid_stream() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x21, 0x01, 0xE0, 0x01, 0x00, 0xEA, 0x21, 0x02]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.ion_version(), (1, 0));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_unsupported_ivm() {
+        let mut cursor = BinaryIonCursor::new(io::Cursor::new(vec![0xE0, 0x02, 0x00, 0xEA]));
+        assert!(cursor.next().is_err());
+    }
+
+    #[test]
+    fn test_read_malformed_ivm() {
+        let mut cursor = BinaryIonCursor::new(io::Cursor::new(vec![0xE0, 0x01, 0x00, 0xEB]));
+        assert!(cursor.next().is_err());
+    }
+
+    #[test]
+    fn test_read_incomplete_ivm() {
+        let mut cursor = BinaryIonCursor::new(io::Cursor::new(vec![0xE0, 0x01]));
+        assert!(cursor.next().is_err());
+    }
+
+    #[test]
+    fn test_read_ivm_in_container() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0xB4, 0xE0, 0x01, 0x00, 0xEA]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert!(cursor.next().is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_read_null_null() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x0F]);
@@ -1110,6 +1477,117 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_i64_min() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x38, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::I64));
+        assert_eq!(cursor.read_i64()?, Some(i64::MIN));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_i64_padded() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x22, 0x00, 0x05, // 5, padded to 2 bytes
+            0x3A, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // -i64::MAX
+            0x3E, 0x8E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x2A, // -42, padded to 14 bytes
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::I64));
+        assert_eq!(cursor.read_i64()?, Some(5));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::I64));
+        assert_eq!(cursor.read_i64()?, Some(-i64::MAX));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::I64));
+        assert_eq!(cursor.read_any_int()?, Some(AnyInt::I64(-42)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_integer_size_of_8_byte_values() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, // 1
+            0x28, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // i64::MAX
+            0x28, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // i64::MAX + 1
+            0x38, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, // i64::MIN - 1
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::I64));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::I64));
+        assert_eq!(cursor.read_i64()?, Some(i64::MAX));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::BigInt));
+        assert!(cursor.read_i64().is_err());
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::BigInt));
+        let expected = BigInt::from(i64::MIN) - 1;
+        assert_eq!(cursor.read_big_int()?, Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_i64_overflow() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x28, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // i64::MAX + 1
+            0x29, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 2^64
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert!(cursor.read_i64().is_err());
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert!(cursor.read_i64().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_big_int() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x21, 0x07, // 7
+            0x39, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // -2^64
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::I64));
+        assert_eq!(cursor.read_big_int()?, Some(BigInt::from(7)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::BigInt));
+        let expected = -BigInt::from(u64::MAX) - 1;
+        assert_eq!(cursor.read_big_int()?, Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_any_int() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x21, 0x07, // 7
+            0x28, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // i64::MAX
+            0x28, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // i64::MAX + 1
+            0x2F, // null.int
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_any_int()?, Some(AnyInt::I64(7)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert!(matches_i64(cursor.read_any_int()?, i64::MAX));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        let expected = AnyInt::BigInt(BigInt::from(i64::MAX) + 1);
+        assert_eq!(cursor.read_any_int()?, Some(expected));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, true)));
+        assert_eq!(cursor.integer_size(), None);
+        assert_eq!(cursor.read_any_int()?, None);
+        Ok(())
+    }
+
+    // AnyInt equality compares values numerically, so this also checks the representation.
+    fn matches_i64(any_int: Option<AnyInt>, expected: i64) -> bool {
+        match any_int {
+            Some(AnyInt::I64(value)) => value == expected,
+            _ => false,
+        }
+    }
+
     #[test]
     fn test_read_f64_zero() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x40]);
@@ -1157,6 +1635,110 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_decimal_negative_zero() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x52, 0xC1, 0x80]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        let decimal = cursor.read_decimal()?.unwrap();
+        assert_eq!(decimal, Decimal::negative_zero_with_exponent(-1));
+        assert_ne!(decimal, Decimal::new(0, -1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_decimal_big_coefficient() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x5A, 0xC2, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        let coefficient = BigUint::from(u64::MAX) + 1u32;
+        let expected = Decimal::new(Coefficient::new(Sign::Negative, coefficient), -2);
+        assert_eq!(cursor.read_decimal()?, Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_precision() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x63, 0xC0, 0x0F, 0xD0, // 2000T
+            0x65, 0xC0, 0x0F, 0xD0, 0x81, 0x81, // 2000-01-01T
+            0x67, 0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, // 2000-01-01T00:00Z
+            0x68, 0xC0, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80, // 2000-01-01T00:00:00-00:00
+            0x6A, 0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80, 0xC3, 0x7B, // ...00:00.123Z
+        ]);
+        let builder = Timestamp::with_ymd(2000, 1, 1);
+        let expected = vec![
+            Timestamp::with_year(2000).build()?,
+            builder.clone().build()?,
+            builder
+                .clone()
+                .with_hour_and_minute(0, 0)
+                .build_at_offset(0)?,
+            builder
+                .clone()
+                .with_hms(0, 0, 0)
+                .build_at_unknown_offset()?,
+            builder
+                .clone()
+                .with_hms(0, 0, 0)
+                .with_milliseconds(123)
+                .build_at_offset(0)?,
+        ];
+        for timestamp in expected {
+            assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+            assert_eq!(cursor.read_timestamp()?, Some(timestamp));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_with_offset() -> IonResult<()> {
+        // 2000-01-01T05:30:00+05:30, which binary Ion encodes as 2000-01-01T00:00:00 in UTC
+        let mut cursor =
+            ion_cursor_for(&[0x69, 0x02, 0xCA, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        let expected = Timestamp::with_ymd(2000, 1, 1)
+            .with_hms(5, 30, 0)
+            .build_at_offset(330)?;
+        assert_eq!(cursor.read_timestamp()?, Some(expected));
+        Ok(())
+    }
+
+    #[rstest]
+    // 2000-01-01T00:00+X, where X is 2^32 minutes
+    #[case::offset_overflows_i32(&[0x6C, 0x10, 0x00, 0x00, 0x00, 0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80])]
+    // 2000-01-01T00:00+X, where X is 2^30 minutes, which overflows an i32 when converted to seconds
+    #[case::offset_seconds_overflow_i32(&[0x6C, 0x04, 0x00, 0x00, 0x00, 0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80])]
+    fn test_read_timestamp_offset_out_of_range(#[case] bytes: &[u8]) -> IonResult<()> {
+        let mut cursor = ion_cursor_for(bytes);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert!(cursor.read_timestamp().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_invalid_fractional_seconds() -> IonResult<()> {
+        // The fractional seconds are 10d-1, which is not less than 1
+        let mut cursor = ion_cursor_for(&[
+            0x6A, 0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80, 0xC1, 0x0A,
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert!(cursor.read_timestamp().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_fractional_seconds_exponent_overflows_u32() -> IonResult<()> {
+        // The fractional seconds are 5d-4294967299, whose exponent does not fit in a u32
+        let mut cursor = ion_cursor_for(&[
+            0x6E, 0x8E, 0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80, 0x50, 0x00, 0x00, 0x00,
+            0x83, 0x05,
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert!(cursor.read_timestamp().is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_read_symbol_10() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x71, 0x0A]);
@@ -15,7 +15,8 @@ use crate::{
     },
 };
 
-const DECIMAL_BUFFER_SIZE: usize = 16;
+// Large enough to hold an exponent VarInt (up to 10 bytes) and an i64 coefficient (up to 9 bytes).
+const DECIMAL_BUFFER_SIZE: usize = 32;
 const DECIMAL_POSITIVE_ZERO: Decimal = Decimal {
     coefficient: Coefficient {
         sign: Sign::Positive,
@@ -56,7 +57,11 @@ where
 
         // If the coefficient is small enough to safely fit in an i64, use that to avoid
         // allocating.
-        if let Some(small_coefficient) = decimal.coefficient.as_i64() {
+        if decimal.coefficient.is_negative_zero() {
+            // A negative zero coefficient is encoded as a sign bit with no magnitude bits.
+            self.write_all(&[0b1000_0000])?;
+            bytes_written += 1;
+        } else if let Some(small_coefficient) = decimal.coefficient.as_i64() {
             // From the spec: "The subfield should not be present (that is, it
             // has zero length) when the coefficient’s value is (positive)
             // zero."
@@ -102,8 +107,16 @@ where
         let mut bytes_written: usize = 0;
         // First encode the decimal. We need to know the encoded length before
         // we can compute and write out the type descriptor.
-        let mut encoded: ArrayVec<u8, DECIMAL_BUFFER_SIZE> = ArrayVec::new();
-        encoded.encode_decimal(decimal)?;
+        // Coefficients that fit in an i64 are encoded on the stack; larger ones require a Vec.
+        let mut small_buffer: ArrayVec<u8, DECIMAL_BUFFER_SIZE> = ArrayVec::new();
+        let mut large_buffer: Vec<u8> = Vec::new();
+        let encoded: &[u8] = if decimal.coefficient.as_i64().is_some() {
+            small_buffer.encode_decimal(decimal)?;
+            &small_buffer[..]
+        } else {
+            large_buffer.encode_decimal(decimal)?;
+            &large_buffer[..]
+        };
 
         let type_descriptor: u8;
         if encoded.len() <= MAX_INLINE_LENGTH {
@@ -118,7 +131,7 @@ where
         }
 
         // Now we can write out the encoded decimal!
-        self.write(&encoded[..])?;
+        self.write(encoded)?;
         bytes_written += encoded.len();
 
         Ok(bytes_written)
@@ -143,6 +156,9 @@ mod binary_decimal_tests {
     #[case::exactly_zero(Decimal::new(0, 0), 1)]
     #[case::zero_with_nonzero_exp(Decimal::new(0, 10), 2)]
     #[case::meaning_of_life(Decimal::new(42, 0), 3)]
+    #[case::negative_zero(Decimal::negative_zero(), 3)]
+    #[case::negative_zero_with_exp(Decimal::negative_zero_with_exponent(-2), 3)]
+    #[case::big_coefficient(Decimal::new(u128::MAX, -40), 20)]
     fn bytes_written(#[case] input: Decimal, #[case] expected: usize) -> IonResult<()> {
         let mut buf = vec![];
         let written = buf.encode_decimal_value(&input)?;
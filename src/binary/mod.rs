@@ -3,6 +3,7 @@
 //! This module provides the necessary structures and logic to read values from a binary Ion
 //! data stream.
 
+pub mod binary_writer;
 pub(crate) mod constants;
 pub(crate) mod cursor;
 pub mod decimal;
@@ -107,8 +107,20 @@ where
 
         // First encode the timestamp. We need to know the encoded length before
         // we can compute and write out the type descriptor.
-        let mut encoded: ArrayVec<u8, MAX_TIMESTAMP_LENGTH> = ArrayVec::new();
-        encoded.encode_timestamp(timestamp)?;
+        // Fractional seconds with more than nanosecond precision can be arbitrarily long, so
+        // they are encoded into a Vec instead of on the stack.
+        let mut small_buffer: ArrayVec<u8, MAX_TIMESTAMP_LENGTH> = ArrayVec::new();
+        let mut large_buffer: Vec<u8> = Vec::new();
+        let encoded: &[u8] = match timestamp.fractional_seconds {
+            Some(Mantissa::Arbitrary(_)) => {
+                large_buffer.encode_timestamp(timestamp)?;
+                &large_buffer[..]
+            }
+            _ => {
+                small_buffer.encode_timestamp(timestamp)?;
+                &small_buffer[..]
+            }
+        };
 
         // Write the type descriptor and length.
         let type_descriptor: u8;
@@ -124,8 +136,8 @@ where
         }
 
         // Now we can write out the encoded timestamp!
-        self.write(&encoded[..])?;
-        bytes_written += &encoded[..].len();
+        self.write(encoded)?;
+        bytes_written += encoded.len();
 
         Ok(bytes_written)
     }
@@ -1,6 +1,9 @@
 use std::io::Write;
 use std::mem;
 
+use num_bigint::BigUint;
+use num_traits::Zero;
+
 use crate::data_source::IonDataSource;
 use crate::result::{decoding_error, IonResult};
 
@@ -119,6 +122,27 @@ pub fn encode_uint(magnitude: u64) -> EncodedUInt {
     }
 }
 
+/// Returns the arbitrarily large magnitude as big-endian bytes. Like [`encode_uint`], leading zero
+/// octets are not part of the representation, so a magnitude of zero is encoded as an empty
+/// slice.
+///
+/// ```
+/// use ion_rs::binary::uint;
+/// use num_bigint::BigUint;
+///
+/// let repr = uint::encode_big_uint(&BigUint::from(u128::MAX));
+/// assert_eq!(&[0xFF; 16], repr.as_slice());
+///
+/// let zero = uint::encode_big_uint(&BigUint::from(0u8));
+/// assert!(zero.is_empty());
+/// ```
+pub fn encode_big_uint(magnitude: &BigUint) -> Vec<u8> {
+    if magnitude.is_zero() {
+        return Vec::new();
+    }
+    magnitude.to_bytes_be()
+}
+
 #[cfg(test)]
 mod tests {
     use super::DecodedUInt;
@@ -27,6 +27,8 @@ const VARINT_NEGATIVE_ZERO: u8 = 0xC0;
 pub struct VarInt {
     size_in_bytes: usize,
     value: VarIntStorage,
+    // [VarIntStorage] cannot represent -0, so the sign is stored separately.
+    is_negative: bool,
 }
 
 const MAGNITUDE_BITS_IN_FINAL_BYTE: usize = 6;
@@ -51,6 +53,7 @@ impl VarInt {
             return Ok(VarInt {
                 size_in_bytes: 1,
                 value: magnitude * sign,
+                is_negative: !is_positive,
             });
         }
 
@@ -73,6 +76,7 @@ impl VarInt {
         Ok(VarInt {
             size_in_bytes: encoded_size_in_bytes,
             value: magnitude * sign,
+            is_negative: !is_positive,
         })
     }
 
@@ -150,6 +154,13 @@ impl VarInt {
         self.value
     }
 
+    /// Returns true if the encoded value was negative zero (`-0`). Binary timestamps use this
+    /// to indicate an unknown offset.
+    #[inline(always)]
+    pub fn is_negative_zero(&self) -> bool {
+        self.is_negative && self.value == 0
+    }
+
     /// Returns the number of bytes that were read from the data source to construct this
     /// signed integer
     #[inline(always)]
@@ -201,6 +212,16 @@ mod tests {
         assert_eq!(var_int.value(), 0);
     }
 
+    #[test]
+    fn test_read_var_int_negative_zero() {
+        let var_int = VarInt::read(&mut Cursor::new(&[0b1100_0000])).expect(ERROR_MESSAGE);
+        assert_eq!(var_int.size_in_bytes(), 1);
+        assert_eq!(var_int.value(), 0);
+        assert!(var_int.is_negative_zero());
+        let var_int = VarInt::read(&mut Cursor::new(&[0b1000_0000])).expect(ERROR_MESSAGE);
+        assert!(!var_int.is_negative_zero());
+    }
+
     #[test]
     fn test_read_var_int_min_negative_two_byte_encoding() {
         let var_int =
@@ -5,14 +5,17 @@ use std::{io, mem};
 use bigdecimal::BigDecimal;
 use bytes::BufMut;
 use chrono::{DateTime, FixedOffset};
+use num_bigint::{BigInt, Sign};
 
 use crate::binary::constants::v1_0::IVM;
 use crate::binary::uint::DecodedUInt;
 use crate::binary::var_uint::VarUInt;
+use crate::cursor::RawSymbolToken;
 use crate::result::{illegal_operation, IonResult};
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
+use crate::writer::IonWriter;
 use crate::IonType;
 
 use super::decimal::DecimalBinaryEncoder;
@@ -176,6 +179,14 @@ impl<W: Write> BinarySystemWriter<W> {
         }
     }
 
+    /// Creates a new BinarySystemWriter that does not write an Ion version marker. Its output can
+    /// be spliced into a stream that another writer has already started.
+    pub(crate) fn new_without_ivm(out: W) -> BinarySystemWriter<W> {
+        let mut writer = BinarySystemWriter::new(out);
+        writer.ivm_needed = false;
+        writer
+    }
+
     // Uses the provided closure to encode data to the buffer. Returns the range of the buffer
     // now occupied by the encoded bytes.
     #[inline]
@@ -410,7 +421,8 @@ impl<W: Write> BinarySystemWriter<W> {
     /// Writes an Ion integer with the specified value.
     pub fn write_i64(&mut self, value: i64) -> IonResult<()> {
         self.write_scalar(|enc_buffer| {
-            let magnitude = value.abs() as u64;
+            // `unsigned_abs` (unlike `abs`) does not overflow when value is i64::MIN.
+            let magnitude = value.unsigned_abs();
             let encoded = uint::encode_uint(magnitude);
             let bytes_to_write = encoded.as_bytes();
 
@@ -429,6 +441,27 @@ impl<W: Write> BinarySystemWriter<W> {
         })
     }
 
+    /// Writes an Ion integer with the specified value, which may be too large to fit in an i64.
+    pub fn write_big_int(&mut self, value: &BigInt) -> IonResult<()> {
+        self.write_scalar(|enc_buffer| {
+            let magnitude = uint::encode_big_uint(value.magnitude());
+            let encoded_length = magnitude.len();
+            let type_code: u8 = match value.sign() {
+                Sign::Minus => 0x30,
+                Sign::NoSign | Sign::Plus => 0x20,
+            };
+
+            if encoded_length <= MAX_INLINE_LENGTH {
+                enc_buffer.push(type_code | encoded_length as u8);
+            } else {
+                enc_buffer.push(type_code | 0x0E);
+                VarUInt::write_u64(enc_buffer, encoded_length as u64)?;
+            }
+            enc_buffer.extend_from_slice(magnitude.as_slice());
+            Ok(())
+        })
+    }
+
     /// Writes an Ion float with the specified value.
     pub fn write_f32(&mut self, value: f32) -> IonResult<()> {
         self.write_scalar(|enc_buffer| {
@@ -459,24 +492,37 @@ impl<W: Write> BinarySystemWriter<W> {
 
     /// Writes an Ion decimal with the specified value.
     pub fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()> {
+        // TODO: Currently clones. Revisit this API, similar to the comment
+        // on `write_datetime`.
+        let decimal: Decimal = value.clone().into();
+        self.write_decimal(&decimal)
+    }
+
+    /// Writes an Ion decimal with the specified value. Unlike [BinarySystemWriter::write_big_decimal],
+    /// this preserves negative zero.
+    pub fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
         self.write_scalar(|enc_buffer| {
-            // TODO: Currently clones. Revisit this API, similar to the comment
-            // on `write_datetime`.
-            let decimal: Decimal = value.clone().into();
-            let _ = enc_buffer.encode_decimal_value(&decimal);
+            enc_buffer.encode_decimal_value(value)?;
             Ok(())
         })
     }
 
     /// Writes an Ion timestamp with the specified value.
     pub fn write_datetime(&mut self, value: &DateTime<FixedOffset>) -> IonResult<()> {
+        // TODO: Currently this clones the Chrono type so we can make a
+        // Timestamp. In #273 we discuss traits that will avoid this clone.
+        // However, this API (`write_datetime`) is probably also not quite
+        // right.
+        let timestamp: Timestamp = value.clone().into();
+        self.write_timestamp(&timestamp)
+    }
+
+    /// Writes an Ion timestamp with the specified value. Unlike
+    /// [BinarySystemWriter::write_datetime], this preserves the timestamp's precision and
+    /// unknown offsets.
+    pub fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
         self.write_scalar(|enc_buffer| {
-            // TODO: Currently this clones the Chrono type so we can make a
-            // Timestamp. In #273 we discuss traits that will avoid this clone.
-            // However, this API (`write_datetime`) is probably also not quite
-            // right.
-            let timestamp: Timestamp = value.clone().into();
-            let _ = enc_buffer.encode_timestamp_value(&timestamp);
+            enc_buffer.encode_timestamp_value(value)?;
             Ok(())
         })
     }
@@ -724,6 +770,12 @@ impl<W: Write> BinarySystemWriter<W> {
         &mut self.out
     }
 
+    /// Consumes the writer and returns the underlying io::Write implementation. Any data that has
+    /// not been flushed is discarded.
+    pub fn into_output(self) -> W {
+        self.out
+    }
+
     /// Writes any buffered data to the sink. This method can only be called when the writer is at
     /// the top level.
     pub fn flush(&mut self) -> IonResult<()> {
@@ -773,6 +825,103 @@ impl<W: Write> BinarySystemWriter<W> {
     }
 }
 
+impl<W: Write> IonWriter for BinarySystemWriter<W> {
+    fn set_annotations(&mut self, annotations: &[RawSymbolToken]) -> IonResult<()> {
+        let annotation_ids = annotations
+            .iter()
+            .map(expect_symbol_id)
+            .collect::<IonResult<Vec<SymbolId>>>()?;
+        self.set_annotation_ids(&annotation_ids);
+        Ok(())
+    }
+
+    fn set_field_name(&mut self, name: RawSymbolToken) -> IonResult<()> {
+        let field_id = expect_symbol_id(&name)?;
+        self.set_field_id(field_id);
+        Ok(())
+    }
+
+    fn write_symbol(&mut self, value: RawSymbolToken) -> IonResult<()> {
+        let symbol_id = expect_symbol_id(&value)?;
+        self.write_symbol_id(symbol_id)
+    }
+
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        BinarySystemWriter::write_null(self, ion_type)
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        BinarySystemWriter::write_bool(self, value)
+    }
+
+    fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        BinarySystemWriter::write_i64(self, value)
+    }
+
+    fn write_big_int(&mut self, value: &BigInt) -> IonResult<()> {
+        BinarySystemWriter::write_big_int(self, value)
+    }
+
+    fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        BinarySystemWriter::write_f32(self, value)
+    }
+
+    fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        BinarySystemWriter::write_f64(self, value)
+    }
+
+    fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()> {
+        BinarySystemWriter::write_big_decimal(self, value)
+    }
+
+    fn write_datetime(&mut self, value: &DateTime<FixedOffset>) -> IonResult<()> {
+        BinarySystemWriter::write_datetime(self, value)
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        BinarySystemWriter::write_decimal(self, value)
+    }
+
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        BinarySystemWriter::write_timestamp(self, value)
+    }
+
+    fn write_string(&mut self, value: &str) -> IonResult<()> {
+        BinarySystemWriter::write_string(self, value)
+    }
+
+    fn write_clob(&mut self, value: &[u8]) -> IonResult<()> {
+        BinarySystemWriter::write_clob(self, value)
+    }
+
+    fn write_blob(&mut self, value: &[u8]) -> IonResult<()> {
+        BinarySystemWriter::write_blob(self, value)
+    }
+
+    fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
+        BinarySystemWriter::step_in(self, ion_type)
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        BinarySystemWriter::step_out(self)
+    }
+
+    fn flush(&mut self) -> IonResult<()> {
+        BinarySystemWriter::flush(self)
+    }
+}
+
+// The BinarySystemWriter does not manage a symbol table, so it can only write symbol IDs.
+fn expect_symbol_id(token: &RawSymbolToken) -> IonResult<SymbolId> {
+    match token {
+        RawSymbolToken::SymbolId(sid) => Ok(*sid),
+        RawSymbolToken::Text(text) => illegal_operation(format!(
+            "The BinarySystemWriter cannot write symbol text ('{}'); use a symbol ID instead.",
+            text
+        )),
+    }
+}
+
 #[cfg(test)]
 mod writer_tests {
     use std::fmt::Debug;
@@ -885,13 +1034,32 @@ mod writer_tests {
     #[test]
     fn binary_writer_ints() -> IonResult<()> {
         binary_writer_scalar_test(
-            &[-24_601, -17, -1, 0, 1, 17, 24_601],
+            &[i64::MIN, -24_601, -17, -1, 0, 1, 17, 24_601, i64::MAX],
             IonType::Integer,
             |writer, v| writer.write_i64(*v),
             |reader| reader.read_i64(),
         )
     }
 
+    #[test]
+    fn binary_writer_big_ints() -> IonResult<()> {
+        let values: Vec<BigInt> = vec![
+            BigInt::from(0),
+            BigInt::from(-17),
+            BigInt::from(u64::MAX) + 1,
+            -BigInt::from(u128::MAX),
+            // Too long for the length to fit in the type descriptor
+            BigInt::from(1) << 160,
+            -(BigInt::from(1) << 160),
+        ];
+        binary_writer_scalar_test(
+            &values,
+            IonType::Integer,
+            |writer, v| writer.write_big_int(v),
+            |reader| reader.read_big_int(),
+        )
+    }
+
     #[test]
     fn binary_writer_floats() -> IonResult<()> {
         binary_writer_scalar_test(
@@ -943,6 +1111,54 @@ mod writer_tests {
         )
     }
 
+    #[test]
+    fn binary_writer_ion_decimals() -> IonResult<()> {
+        let values = [
+            Decimal::new(-24601, -3),
+            Decimal::negative_zero(),
+            Decimal::negative_zero_with_exponent(-2),
+            Decimal::new(0, 0),
+            Decimal::new(0, 3),
+            Decimal::new(17, 1),
+            Decimal::new(u128::MAX, -12),
+        ];
+        binary_writer_scalar_test(
+            &values,
+            IonType::Decimal,
+            |writer, v| writer.write_decimal(v),
+            |reader| reader.read_decimal(),
+        )
+    }
+
+    #[test]
+    fn binary_writer_ion_timestamps() -> IonResult<()> {
+        let values = [
+            Timestamp::with_year(2021).build()?,
+            Timestamp::with_year(2021).with_month(3).build()?,
+            Timestamp::with_ymd(2021, 3, 4).build()?,
+            Timestamp::with_ymd(2021, 3, 4)
+                .with_hour_and_minute(5, 6)
+                .build_at_unknown_offset()?,
+            Timestamp::with_ymd(2021, 3, 4)
+                .with_hms(5, 6, 7)
+                .build_at_offset(-300)?,
+            Timestamp::with_ymd(2021, 3, 4)
+                .with_hms(5, 6, 7)
+                .with_milliseconds(80)
+                .build_at_offset(60)?,
+            Timestamp::with_ymd(2021, 3, 4)
+                .with_hms(5, 6, 7)
+                .with_fractional_seconds(Decimal::new(123456789012u64, -12))
+                .build_at_unknown_offset()?,
+        ];
+        binary_writer_scalar_test(
+            &values,
+            IonType::Timestamp,
+            |writer, v| writer.write_timestamp(v),
+            |reader| reader.read_timestamp(),
+        )
+    }
+
     #[test]
     fn binary_writer_symbols() -> IonResult<()> {
         binary_writer_scalar_test(
@@ -1055,7 +1271,11 @@ mod writer_tests {
 
     fn expect_annotations(reader: &TestReader, annotations: &[&str]) {
         assert_eq!(
-            reader.annotations().collect::<Vec<&str>>().as_slice(),
+            reader
+                .annotations()
+                .collect::<IonResult<Vec<&str>>>()
+                .unwrap()
+                .as_slice(),
             annotations
         );
     }
@@ -0,0 +1,112 @@
+use std::collections::{BTreeMap, HashMap};
+
+/// A named, versioned list of symbols that local symbol tables can import by reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedSymbolTable {
+    name: String,
+    version: usize,
+    symbols: Vec<Option<String>>,
+}
+
+impl SharedSymbolTable {
+    /// Constructs a new shared symbol table. A `None` in `symbols` reserves a symbol ID whose
+    /// text is unknown.
+    pub fn new<N: Into<String>>(
+        name: N,
+        version: usize,
+        symbols: Vec<Option<String>>,
+    ) -> SharedSymbolTable {
+        SharedSymbolTable {
+            name: name.into(),
+            version,
+            symbols,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    // The symbols defined by the table, in the order that they will be imported.
+    pub fn symbols(&self) -> &[Option<String>] {
+        &self.symbols
+    }
+}
+
+/// A collection of shared symbol tables that a [`Reader`](crate::Reader) can use to resolve
+/// the `imports` of a local symbol table.
+pub trait Catalog {
+    /// Returns the shared symbol table with the specified name and version, if it exists.
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable>;
+
+    /// Returns the shared symbol table with the specified name and the highest version, if any
+    /// version exists.
+    fn get_table(&self, name: &str) -> Option<&SharedSymbolTable>;
+}
+
+/// A [`Catalog`] that stores its shared symbol tables in memory.
+#[derive(Debug, Default)]
+pub struct MapCatalog {
+    tables_by_name: HashMap<String, BTreeMap<usize, SharedSymbolTable>>,
+}
+
+impl MapCatalog {
+    /// Constructs a new catalog that does not contain any shared symbol tables.
+    pub fn new() -> MapCatalog {
+        MapCatalog::default()
+    }
+
+    /// Adds the provided table to the catalog, replacing any table that has the same name
+    /// and version.
+    pub fn insert_table(&mut self, table: SharedSymbolTable) {
+        self.tables_by_name
+            .entry(table.name.clone())
+            .or_insert_with(BTreeMap::new)
+            .insert(table.version, table);
+    }
+}
+
+impl Catalog for MapCatalog {
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable> {
+        self.tables_by_name.get(name)?.get(&version)
+    }
+
+    fn get_table(&self, name: &str) -> Option<&SharedSymbolTable> {
+        let (_version, table) = self.tables_by_name.get(name)?.iter().next_back()?;
+        Some(table)
+    }
+}
+
+#[cfg(test)]
+mod catalog_tests {
+    use super::*;
+
+    fn table(name: &str, version: usize) -> SharedSymbolTable {
+        SharedSymbolTable::new(name, version, vec![Some(format!("{}_{}", name, version))])
+    }
+
+    #[test]
+    fn get_tables() {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(table("foo", 2));
+        catalog.insert_table(table("foo", 1));
+        catalog.insert_table(table("bar", 1));
+
+        assert_eq!(
+            Some(&table("foo", 1)),
+            catalog.get_table_with_version("foo", 1)
+        );
+        assert_eq!(
+            Some(&table("foo", 2)),
+            catalog.get_table_with_version("foo", 2)
+        );
+        assert_eq!(None, catalog.get_table_with_version("foo", 3));
+        assert_eq!(Some(&table("foo", 2)), catalog.get_table("foo"));
+        assert_eq!(Some(&table("bar", 1)), catalog.get_table("bar"));
+        assert_eq!(None, catalog.get_table("baz"));
+    }
+}
@@ -1,8 +1,13 @@
 use crate::data_source::IonDataSource;
 use crate::result::IonResult;
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
 use crate::types::{IonType, SymbolId};
+use crate::value::AnyInt;
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, FixedOffset};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 
 /**
  * This trait captures the format-agnostic parser functionality needed to navigate within an Ion
@@ -39,6 +44,25 @@ pub trait Cursor {
     /// field's name; otherwise, returns None.
     fn field_id(&self) -> Option<SymbolId>;
 
+    /// Returns an iterator over the current value's annotations as they were encoded in the stream.
+    /// Unlike [annotation_ids](Cursor::annotation_ids), this includes annotations whose text was
+    /// written out inline. The default implementation is suitable for formats that only encode
+    /// annotations as symbol IDs.
+    fn raw_annotations<'a>(&'a self) -> Box<dyn Iterator<Item = RawSymbolToken<'a>> + 'a> {
+        Box::new(
+            self.annotation_ids()
+                .iter()
+                .map(|sid| RawSymbolToken::SymbolId(*sid)),
+        )
+    }
+
+    /// If the current value is a field within a struct, returns that field's name as it was
+    /// encoded in the stream; otherwise, returns None. The default implementation is suitable for
+    /// formats that only encode field names as symbol IDs.
+    fn raw_field_name(&self) -> Option<RawSymbolToken> {
+        self.field_id().map(RawSymbolToken::SymbolId)
+    }
+
     /// If the current value is a null, returns the Ion type of the null; otherwise,
     /// returns None.
     fn read_null(&mut self) -> IonResult<Option<IonType>>;
@@ -46,9 +70,32 @@ pub trait Cursor {
     /// If the current value is a boolean, returns its value as a bool; otherwise, returns None.
     fn read_bool(&mut self) -> IonResult<Option<bool>>;
 
+    /// If the current value is a non-null integer, returns the size of the integer representation
+    /// needed to read it; otherwise, returns None.
+    fn integer_size(&self) -> Option<IntegerSize>;
+
     /// If the current value is an integer, returns its value as an i64; otherwise, returns None.
+    /// If the integer is too large to fit in an i64, returns Err.
     fn read_i64(&mut self) -> IonResult<Option<i64>>;
 
+    /// If the current value is an integer, returns its value as a BigInt; otherwise, returns None.
+    fn read_big_int(&mut self) -> IonResult<Option<BigInt>>;
+
+    /// If the current value is an integer, returns its value as an AnyInt; otherwise, returns None.
+    /// The AnyInt will only hold a BigInt if the value is too large to fit in an i64.
+    fn read_any_int(&mut self) -> IonResult<Option<AnyInt>> {
+        match self.integer_size() {
+            Some(IntegerSize::I64) => Ok(self.read_i64()?.map(AnyInt::I64)),
+            Some(IntegerSize::BigInt) => {
+                Ok(self.read_big_int()?.map(|value| match value.to_i64() {
+                    Some(value) => AnyInt::I64(value),
+                    None => AnyInt::BigInt(value),
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+
     /// If the current value is a float, returns its value as an f32; otherwise, returns None.
     fn read_f32(&mut self) -> IonResult<Option<f32>>;
 
@@ -59,6 +106,10 @@ pub trait Cursor {
     /// returns None.
     fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
 
+    /// If the current value is a decimal, returns its value as a Decimal; otherwise, returns None.
+    /// Unlike [read_big_decimal](Self::read_big_decimal), this preserves negative zero.
+    fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
+
     /// If the current value is a string, returns its value as a String; otherwise, returns None.
     fn read_string(&mut self) -> IonResult<Option<String>>;
 
@@ -83,6 +134,13 @@ pub trait Cursor {
     /// If the current value is a symbol, returns its value as a SymbolId; otherwise, returns None.
     fn read_symbol_id(&mut self) -> IonResult<Option<SymbolId>>;
 
+    /// If the current value is a symbol, returns it as it was encoded in the stream; otherwise,
+    /// returns None. The default implementation is suitable for formats that only encode symbols
+    /// as symbol IDs.
+    fn read_raw_symbol(&mut self) -> IonResult<Option<RawSymbolToken>> {
+        Ok(self.read_symbol_id()?.map(RawSymbolToken::SymbolId))
+    }
+
     /// If the current value is a blob, returns its value as a Vec<u8>; otherwise, returns None.
     fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
 
@@ -107,6 +165,11 @@ pub trait Cursor {
     /// otherwise, returns None.
     fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>>;
 
+    /// If the current value is a timestamp, returns its value as a Timestamp; otherwise, returns
+    /// None. Unlike [read_datetime](Self::read_datetime), this preserves the timestamp's
+    /// precision and whether its offset is known.
+    fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
+
     /// If the current value is a container (i.e. a struct, list, or s-expression), positions the
     /// cursor at the beginning of that container's sequence of child values. If the current value
     /// is not a container, returns Err.
@@ -132,3 +195,20 @@ pub enum StreamItem {
     /// $ion_symbol_table annotation) are still considered values.
     Value(IonType, bool),
 }
+
+/// The integer representation needed to read an integer value without losing data.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum IntegerSize {
+    /// The integer is guaranteed to fit in an i64.
+    I64,
+    /// The integer may be too large to fit in an i64 and should be read as a BigInt.
+    BigInt,
+}
+
+/// A symbol as it was encoded in the stream, before any symbol table lookups have been performed.
+/// Binary Ion always refers to symbols by ID, while text Ion may also spell them out inline.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RawSymbolToken<'a> {
+    SymbolId(SymbolId),
+    Text(&'a str),
+}
@@ -0,0 +1,761 @@
+//! Deserializes Rust data structures from Ion using [serde](https://serde.rs). The mapping
+//! between Rust and Ion types mirrors the one described in [`ser`](crate::ser); in addition:
+//! * Ion decimals can be read as floats, and symbols can be read as strings.
+//! * Blobs and clobs can be read as byte arrays or as sequences of bytes (for example, `Vec<u8>`).
+//! * Unit enum variants can be read from a symbol, a string or an annotated `null`.
+//!
+//! ```
+//! # use ion_rs::result::IonResult;
+//! # fn main() -> IonResult<()> {
+//! let values: Vec<i64> = ion_rs::from_slice(b"[1, 2, 3]")?;
+//! assert_eq!(vec![1, 2, 3], values);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use serde::de::value::{BorrowedStrDeserializer, SeqDeserializer, StringDeserializer};
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::{Deserialize, Deserializer as _};
+
+use crate::binary::constants::v1_0::IVM;
+use crate::cursor::Cursor;
+use crate::result::{decoding_error, decoding_error_raw, IonError, IonResult};
+use crate::ser::{to_ion_text, DECIMAL_NEWTYPE_NAME, TIMESTAMP_NEWTYPE_NAME};
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
+use crate::value::native_reader::decimal_to_f64;
+use crate::value::AnyInt;
+use crate::{BinaryIonCursor, IonType, Reader, TextIonCursor};
+
+/// Deserializes an instance of `T` from a single top-level Ion value. Input that begins with an
+/// Ion version marker is read as binary Ion; all other input is read as text Ion.
+///
+/// The deserialized value never borrows from `data`, so `T` must own all of its contents.
+pub fn from_slice<T>(data: &[u8]) -> IonResult<T>
+where
+    T: DeserializeOwned,
+{
+    if data.starts_with(&IVM) {
+        let cursor = BinaryIonCursor::new(io::Cursor::new(data));
+        return deserialize_one(Reader::new(cursor));
+    }
+    deserialize_one(Reader::new(TextIonCursor::new(data)))
+}
+
+/// Deserializes an instance of `T` from a single top-level Ion value read from `input`. See
+/// [`from_slice`].
+pub fn from_reader<R, T>(mut input: R) -> IonResult<T>
+where
+    R: io::Read,
+    T: DeserializeOwned,
+{
+    let mut data = Vec::new();
+    input.read_to_end(&mut data)?;
+    from_slice(&data)
+}
+
+fn deserialize_one<C: Cursor, T: DeserializeOwned>(reader: Reader<C>) -> IonResult<T> {
+    let mut deserializer = Deserializer::new(reader);
+    let value = match deserializer.deserialize_next()? {
+        Some(value) => value,
+        None => return decoding_error("The input did not contain an Ion value."),
+    };
+    if deserializer.advance()? {
+        return decoding_error("The input contained more than one top-level Ion value.");
+    }
+    Ok(value)
+}
+
+/// Parses `text` as a single non-null Ion value, reading it with the provided `read_*` method.
+pub(crate) fn parse_ion_text<'a, T, F>(text: &'a str, read: F) -> IonResult<T>
+where
+    F: FnOnce(&mut Reader<TextIonCursor<&'a [u8]>>) -> IonResult<Option<T>>,
+{
+    let mut reader = Reader::new(TextIonCursor::new(text.as_bytes()));
+    let value = match reader.next()? {
+        Some((_, false)) => read(&mut reader)?,
+        _ => None,
+    };
+    match (value, reader.next()?) {
+        (Some(value), None) => Ok(value),
+        _ => decoding_error(format!(
+            "Could not parse '{}' as a single Ion value of the expected type.",
+            text
+        )),
+    }
+}
+
+/// A serde [`Deserializer`](de::Deserializer) that reads values from a [`Reader`]. Each call to
+/// [`Deserializer::deserialize_next`] deserializes the next top-level value in the stream.
+pub struct Deserializer<C: Cursor> {
+    reader: Reader<C>,
+    // The value on which the reader is positioned, as returned by Reader::next.
+    current: Option<(IonType, bool)>,
+    // The number of the current value's annotations that have been read as enum variant names.
+    annotations_read: usize,
+}
+
+impl<C: Cursor> Deserializer<C> {
+    /// Creates a deserializer that reads values from the provided [`Reader`], starting with the
+    /// next top-level value in its stream.
+    pub fn new(reader: Reader<C>) -> Self {
+        Deserializer {
+            reader,
+            current: None,
+            annotations_read: 0,
+        }
+    }
+
+    /// Deserializes an instance of `T` from the next top-level value in the stream. Returns
+    /// `Ok(None)` at the end of the stream.
+    pub fn deserialize_next<T: DeserializeOwned>(&mut self) -> IonResult<Option<T>> {
+        if !self.advance()? {
+            return Ok(None);
+        }
+        T::deserialize(self).map(Some)
+    }
+
+    /// Consumes the deserializer and returns the wrapped [`Reader`].
+    pub fn into_inner(self) -> Reader<C> {
+        self.reader
+    }
+
+    // Moves the reader to the next value at the current depth. Returns false if there are no more
+    // values.
+    fn advance(&mut self) -> IonResult<bool> {
+        self.current = self.reader.next()?;
+        self.annotations_read = 0;
+        Ok(self.current.is_some())
+    }
+
+    fn current(&self) -> IonResult<(IonType, bool)> {
+        self.current
+            .ok_or_else(|| decoding_error_raw("The deserializer is not positioned on a value."))
+    }
+
+    // Returns the first of the current value's annotations that has not been read yet.
+    fn next_annotation(&mut self) -> IonResult<Option<String>> {
+        let annotation = match self.reader.annotations().nth(self.annotations_read) {
+            Some(annotation) => annotation?.to_string(),
+            None => return Ok(None),
+        };
+        self.annotations_read += 1;
+        Ok(Some(annotation))
+    }
+
+    /// Calls the provided `read_*` method, treating a value of the wrong type as an error.
+    fn read<T, F>(&mut self, read: F) -> IonResult<T>
+    where
+        F: FnOnce(&mut Reader<C>) -> IonResult<Option<T>>,
+    {
+        match read(&mut self.reader)? {
+            Some(value) => Ok(value),
+            None => decoding_error(format!(
+                "Could not read the current value as a {:?}",
+                self.reader.ion_type()
+            )),
+        }
+    }
+
+    fn visit_container<V, F>(&mut self, visit: F) -> IonResult<V>
+    where
+        F: FnOnce(&mut Self) -> IonResult<V>,
+    {
+        self.reader.step_in()?;
+        let value = visit(self)?;
+        self.reader.step_out()?;
+        Ok(value)
+    }
+}
+
+pub(crate) fn visit_big_int<'de, V: Visitor<'de>>(
+    value: BigInt,
+    visitor: V,
+) -> IonResult<V::Value> {
+    if let Some(value) = value.to_u64() {
+        return visitor.visit_u64(value);
+    }
+    if let Some(value) = value.to_i128() {
+        return visitor.visit_i128(value);
+    }
+    if let Some(value) = value.to_u128() {
+        return visitor.visit_u128(value);
+    }
+    decoding_error(format!(
+        "The integer {} is too large to deserialize.",
+        value
+    ))
+}
+
+impl<'de, 'a, C: Cursor> de::Deserializer<'de> for &'a mut Deserializer<C> {
+    type Error = IonError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        let (ion_type, is_null) = self.current()?;
+        if is_null {
+            return visitor.visit_unit();
+        }
+        match ion_type {
+            IonType::Null => visitor.visit_unit(),
+            IonType::Boolean => visitor.visit_bool(self.read(Reader::read_bool)?),
+            IonType::Integer => match self.read(Reader::read_any_int)? {
+                AnyInt::I64(value) => visitor.visit_i64(value),
+                AnyInt::BigInt(value) => visit_big_int(value, visitor),
+            },
+            IonType::Float => visitor.visit_f64(self.read(Reader::read_f64)?),
+            IonType::Decimal => {
+                visitor.visit_f64(decimal_to_f64(self.read(Reader::read_decimal)?)?)
+            }
+            IonType::Timestamp => {
+                let timestamp = self.read(Reader::read_timestamp)?;
+                visitor.visit_string(to_ion_text(|writer| writer.write_timestamp(&timestamp))?)
+            }
+            IonType::Symbol => visitor.visit_string(self.read(Reader::read_symbol)?),
+            IonType::String => visitor.visit_string(self.read(Reader::read_string)?),
+            IonType::Clob => visitor.visit_byte_buf(self.read(Reader::read_clob_bytes)?),
+            IonType::Blob => visitor.visit_byte_buf(self.read(Reader::read_blob_bytes)?),
+            IonType::List | IonType::SExpression => {
+                self.visit_container(|de| visitor.visit_seq(SeqAccess { de }))
+            }
+            IonType::Struct => self.visit_container(|de| visitor.visit_map(MapAccess { de })),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        match self.current()? {
+            (IonType::Null, _) | (_, true) => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        let bytes = match self.current()? {
+            (IonType::Blob, false) => self.read(Reader::read_blob_bytes)?,
+            (IonType::Clob, false) => self.read(Reader::read_clob_bytes)?,
+            _ => return self.deserialize_any(visitor),
+        };
+        visitor.visit_seq(SeqDeserializer::<_, IonError>::new(bytes.into_iter()))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        match (name, self.current()?) {
+            (DECIMAL_NEWTYPE_NAME, (IonType::Decimal, false)) => {
+                let decimal = self.read(Reader::read_decimal)?;
+                visitor.visit_string(to_ion_text(|writer| writer.write_decimal(&decimal))?)
+            }
+            (DECIMAL_NEWTYPE_NAME, _) | (TIMESTAMP_NEWTYPE_NAME, _) => {
+                self.deserialize_any(visitor)
+            }
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        if let Some(variant) = self.next_annotation()? {
+            return visitor.visit_enum(Enum { de: self, variant });
+        }
+        let variant = match self.current()? {
+            (IonType::Symbol, false) => self.read(Reader::read_symbol)?,
+            (IonType::String, false) => self.read(Reader::read_string)?,
+            _ => {
+                return decoding_error(format!(
+                    "Expected a symbol or an annotated value for enum {}.",
+                    name
+                ))
+            }
+        };
+        let variant: StringDeserializer<IonError> = variant.into_deserializer();
+        visitor.visit_enum(variant)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Deserializes the children of an Ion list or s-expression.
+struct SeqAccess<'a, C: Cursor> {
+    de: &'a mut Deserializer<C>,
+}
+
+impl<'de, 'a, C: Cursor> de::SeqAccess<'de> for SeqAccess<'a, C> {
+    type Error = IonError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> IonResult<Option<T::Value>> {
+        if !self.de.advance()? {
+            return Ok(None);
+        }
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+}
+
+/// Deserializes the fields of an Ion struct.
+struct MapAccess<'a, C: Cursor> {
+    de: &'a mut Deserializer<C>,
+}
+
+impl<'de, 'a, C: Cursor> de::MapAccess<'de> for MapAccess<'a, C> {
+    type Error = IonError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> IonResult<Option<K::Value>> {
+        if !self.de.advance()? {
+            return Ok(None);
+        }
+        let field_name =
+            match self.de.reader.field_name() {
+                Some(text) => text.to_string(),
+                None => return decoding_error(
+                    "Found a struct field whose name is not defined in the current symbol table.",
+                ),
+            };
+        seed.deserialize(MapKeyDeserializer::new(field_name))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> IonResult<V::Value> {
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+/// Deserializes a struct field name as a map key. Map keys are serialized as text, so keys of
+/// other types (like integers) are parsed back out of the field name.
+pub(crate) struct MapKeyDeserializer<'a> {
+    key: Cow<'a, str>,
+}
+
+impl<'a> MapKeyDeserializer<'a> {
+    pub(crate) fn new<K: Into<Cow<'a, str>>>(key: K) -> Self {
+        MapKeyDeserializer { key: key.into() }
+    }
+
+    fn parse<T: FromStr>(&self) -> IonResult<T> {
+        self.key.parse().map_err(|_| {
+            decoding_error_raw(format!(
+                "Could not parse the field name '{}' as a {}.",
+                self.key,
+                std::any::type_name::<T>()
+            ))
+        })
+    }
+}
+
+macro_rules! deserialize_parsed_key {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+                visitor.$visit(self.parse()?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for MapKeyDeserializer<'de> {
+    type Error = IonError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        match self.key {
+            Cow::Borrowed(key) => visitor.visit_borrowed_str(key),
+            Cow::Owned(key) => visitor.visit_string(key),
+        }
+    }
+
+    deserialize_parsed_key! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        match self.key {
+            Cow::Borrowed(key) => visitor.visit_enum(BorrowedStrDeserializer::<IonError>::new(key)),
+            Cow::Owned(key) => {
+                let variant: StringDeserializer<IonError> = key.into_deserializer();
+                visitor.visit_enum(variant)
+            }
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        f32 f64 str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// Deserializes an enum variant whose name was read from an annotation on the current value.
+struct Enum<'a, C: Cursor> {
+    de: &'a mut Deserializer<C>,
+    variant: String,
+}
+
+impl<'de, 'a, C: Cursor> de::EnumAccess<'de> for Enum<'a, C> {
+    type Error = IonError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> IonResult<(V::Value, Self)> {
+        let variant: StringDeserializer<IonError> = self.variant.clone().into_deserializer();
+        let value = seed.deserialize(variant)?;
+        Ok((value, self))
+    }
+}
+
+impl<'de, 'a, C: Cursor> de::VariantAccess<'de> for Enum<'a, C> {
+    type Error = IonError;
+
+    fn unit_variant(self) -> IonResult<()> {
+        <()>::deserialize(self.de)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> IonResult<T::Value> {
+        seed.deserialize(self.de)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> IonResult<V::Value> {
+        self.de.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        self.de.deserialize_map(visitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(TIMESTAMP_NEWTYPE_NAME, TimestampVisitor)
+    }
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an Ion timestamp")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Timestamp, E> {
+        parse_ion_text(v, Reader::read_timestamp).map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Timestamp, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(DECIMAL_NEWTYPE_NAME, DecimalVisitor)
+    }
+}
+
+struct DecimalVisitor;
+
+impl<'de> Visitor<'de> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an Ion decimal")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Decimal, E> {
+        Ok(Decimal::from(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Decimal, E> {
+        Ok(Decimal::from(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Decimal, E> {
+        parse_ion_text(v, Reader::read_decimal).map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Decimal, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl de::Error for IonError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        decoding_error_raw(msg.to_string())
+    }
+}
+
+#[cfg(test)]
+mod serde_tests {
+    use super::*;
+    use crate::ser::to_vec;
+    use crate::value::native_reader::NativeElementReader;
+    use crate::value::reader::ElementReader;
+    use crate::value::writer::{Format, TextKind};
+    use crate::value::{Element, Struct};
+    use rstest::*;
+    use serde::Serialize;
+    use std::collections::{BTreeMap, HashMap};
+    use std::str::from_utf8;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Point,
+        Circle(u32),
+        Line(i32, i32),
+        Rect { width: u64, height: u64 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        id: u64,
+        name: String,
+        initial: char,
+        score: f64,
+        tags: Vec<String>,
+        shapes: Vec<Shape>,
+        nested: Option<Shape>,
+        missing: Option<i32>,
+        #[serde(with = "serde_bytes")]
+        payload: Vec<u8>,
+        created: Timestamp,
+        price: Decimal,
+        counts: BTreeMap<String, i32>,
+        huge: u128,
+        pair: (bool, i8),
+    }
+
+    fn record() -> Record {
+        let mut counts = BTreeMap::new();
+        counts.insert("a".to_string(), 1);
+        counts.insert("b c".to_string(), -2);
+        Record {
+            id: u64::MAX,
+            name: "widget".to_string(),
+            initial: 'w',
+            score: 0.5,
+            tags: vec!["x".to_string(), "y".to_string()],
+            shapes: vec![
+                Shape::Point,
+                Shape::Circle(2),
+                Shape::Line(-1, 1),
+                Shape::Rect {
+                    width: 3,
+                    height: 4,
+                },
+            ],
+            nested: Some(Shape::Circle(5)),
+            missing: None,
+            payload: vec![0, 1, 255],
+            created: Timestamp::with_ymd(2021, 3, 4)
+                .with_hms(5, 6, 7)
+                .build_at_offset(-300)
+                .unwrap(),
+            price: Decimal::new(1999, -2),
+            counts,
+            huge: u128::MAX,
+            pair: (true, -8),
+        }
+    }
+
+    #[rstest]
+    #[case::text(Format::Text(TextKind::Compact))]
+    #[case::pretty(Format::Text(TextKind::Pretty))]
+    #[case::binary(Format::Binary)]
+    fn round_trip(#[case] format: Format) -> IonResult<()> {
+        let expected = record();
+        let bytes = to_vec(&expected, format)?;
+        let actual: Record = from_slice(&bytes)?;
+        assert_eq!(expected, actual);
+        let actual: Record = from_reader(bytes.as_slice())?;
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text(Format::Text(TextKind::Compact))]
+    #[case::binary(Format::Binary)]
+    #[case::json(Format::Json(TextKind::Compact))]
+    fn round_trip_non_string_map_keys(#[case] format: Format) -> IonResult<()> {
+        let ids: HashMap<u32, String> = vec![(1, "one".to_string()), (20, "twenty".to_string())]
+            .into_iter()
+            .collect();
+        let bytes = to_vec(&ids, format)?;
+        assert_eq!(ids, from_slice::<HashMap<u32, String>>(&bytes)?);
+        let flags: BTreeMap<bool, char> = vec![(false, 'n'), (true, 'y')].into_iter().collect();
+        let bytes = to_vec(&flags, format)?;
+        assert_eq!(flags, from_slice::<BTreeMap<bool, char>>(&bytes)?);
+        let initials: BTreeMap<char, i64> = vec![('a', -1), ('b', 2)].into_iter().collect();
+        let bytes = to_vec(&initials, format)?;
+        assert_eq!(initials, from_slice::<BTreeMap<char, i64>>(&bytes)?);
+        Ok(())
+    }
+
+    #[test]
+    fn map_keys_of_the_wrong_type_fail() {
+        assert!(from_slice::<HashMap<u32, i32>>(b"{one: 1}").is_err());
+        assert!(from_slice::<HashMap<u8, i32>>(b"{'256': 1}").is_err());
+    }
+
+    #[test]
+    fn enums_are_annotations() -> IonResult<()> {
+        let shapes = vec![
+            Shape::Point,
+            Shape::Circle(2),
+            Shape::Line(-1, 1),
+            Shape::Rect {
+                width: 3,
+                height: 4,
+            },
+        ];
+        let bytes = to_vec(&shapes, Format::Text(TextKind::Compact))?;
+        assert_eq!(
+            "[Point,Circle::2,Line::[-1,1,],Rect::{width:3,height:4,},]\n",
+            from_utf8(&bytes).unwrap()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nested_enums_are_annotations() -> IonResult<()> {
+        let value: Option<Result<Shape, ()>> = Some(Ok(Shape::Circle(1)));
+        let bytes = to_vec(&value, Format::Binary)?;
+        let element = NativeElementReader.read_one(&bytes)?;
+        let annotations: Vec<_> = element
+            .annotations()
+            .map(|a| a.text().unwrap().to_string())
+            .collect();
+        assert_eq!(vec!["Ok", "Circle"], annotations);
+        assert_eq!(value, from_slice(&bytes)?);
+        Ok(())
+    }
+
+    #[test]
+    fn native_ion_types() -> IonResult<()> {
+        let bytes = to_vec(&record(), Format::Binary)?;
+        let element = NativeElementReader.read_one(&bytes)?;
+        let record = element.as_struct().unwrap();
+        let ion_type = |name: &str| record.get(name).unwrap().ion_type();
+        assert_eq!(IonType::Timestamp, ion_type("created"));
+        assert_eq!(IonType::Decimal, ion_type("price"));
+        assert_eq!(IonType::Blob, ion_type("payload"));
+        assert_eq!(IonType::Integer, ion_type("huge"));
+        assert_eq!(IonType::Null, ion_type("missing"));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::compact(Format::Json(TextKind::Compact))]
+    #[case::pretty(Format::Json(TextKind::Pretty))]
+    fn json_output(#[case] format: Format) -> IonResult<()> {
+        let shapes = vec![Shape::Point, Shape::Point];
+        let bytes = to_vec(&shapes, format)?;
+        assert_eq!(shapes, from_slice::<Vec<Shape>>(&bytes)?);
+        // JSON cannot annotate values with the names of non-unit variants.
+        assert!(to_vec(&Shape::Circle(2), format).is_err());
+        assert!(to_vec(&vec![Shape::Point, Shape::Line(-1, 1)], format).is_err());
+        assert!(to_vec(&Some(Ok::<u8, ()>(1)), format).is_err());
+        let counts: BTreeMap<String, Decimal> = vec![("a".to_string(), Decimal::new(15, -1))]
+            .into_iter()
+            .collect();
+        let bytes = to_vec(&counts, format)?;
+        assert_eq!(counts, from_slice::<BTreeMap<String, Decimal>>(&bytes)?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_ion_text() -> IonResult<()> {
+        assert_eq!(
+            Shape::Rect {
+                width: 3,
+                height: 4
+            },
+            from_slice(b"Rect::{height: 4, width: 3, ignored: [1, {a: 2}]}")?
+        );
+        assert_eq!(Shape::Point, from_slice(b"Point")?);
+        assert_eq!(Shape::Point, from_slice(b"\"Point\"")?);
+        assert_eq!(Shape::Point, from_slice(b"Point::null")?);
+        assert_eq!(vec![1u8, 2], from_slice::<Vec<u8>>(b"{{AQI=}}")?);
+        assert_eq!(2.5f64, from_slice::<f64>(b"2.50")?);
+        assert_eq!("sym", from_slice::<String>(b"sym")?);
+        assert_eq!(u64::MAX, from_slice::<u64>(b"18446744073709551615")?);
+        assert_eq!(None, from_slice::<Option<i32>>(b"null.int")?);
+        assert_eq!(
+            Decimal::negative_zero_with_exponent(-1),
+            from_slice::<Decimal>(b"-0.0")?
+        );
+        assert_eq!(
+            Timestamp::with_year(2021).build().unwrap(),
+            from_slice::<Timestamp>(b"2021T")?
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty(b"")]
+    #[case::trailing_values(b"1 2")]
+    #[case::wrong_type(b"\"one\"")]
+    #[case::unknown_variant(b"Hexagon::1")]
+    #[case::wrong_variant_contents(b"Circle::\"one\"")]
+    fn read_fails(#[case] input: &[u8]) {
+        assert!(from_slice::<Shape>(input).is_err());
+    }
+
+    #[test]
+    fn read_out_of_range_fails() {
+        assert!(from_slice::<u8>(b"256").is_err());
+        assert!(from_slice::<u64>(b"-1").is_err());
+        assert!(from_slice::<i128>(b"340282366920938463463374607431768211456").is_err());
+    }
+}
@@ -0,0 +1,362 @@
+//! Provides [`IonEq`], the equivalence relation defined by the
+//! [Ion data model](https://amzn.github.io/ion-docs/docs/spec.html#the-ion-data-model).
+//!
+//! Rust's [`PartialEq`] implementations in this crate compare values in the way that is most
+//! natural for each Rust type: `0.0 == -0.0`, `NaN != NaN` and the [`Decimal`](crate::types::decimal::Decimal)
+//! values `1.0` and `1.00` are equal because they represent the same number. The Ion data model
+//! is stricter (and in the case of `NaN`, looser) than that. [`IonEq`] implements the data model's
+//! notion of equivalence so that it can be used when comparing values for round-tripping,
+//! in test suites, and anywhere else that two Ion values must be indistinguishable.
+
+use crate::value::{Element, ImportSource, Sequence, Struct, SymbolToken};
+use crate::IonType;
+
+/// Tests two values for equivalence as defined by the Ion data model.
+///
+/// The rules for each type are:
+/// * `null`s are equivalent if they have the same Ion type.
+/// * `int`s are equivalent if they have the same numeric value, regardless of how they are
+///   represented in memory.
+/// * `float`s are equivalent if they have the same value. Unlike [`f64`]'s [`PartialEq`]
+///   implementation, `-0e0` is **not** equivalent to `0e0` and `nan` **is** equivalent to `nan`.
+/// * `decimal`s are equivalent if they have the same coefficient (including its sign) and
+///   exponent. This means that `1.0` is **not** equivalent to `1.00` and that `-0.` is not
+///   equivalent to `0.`.
+/// * `timestamp`s are equivalent if they represent the same point in time with the same precision
+///   and the same offset. `2020T` is not equivalent to `2020-01-01T` and a timestamp with an
+///   unknown offset (`-00:00`) is not equivalent to one in UTC (`Z`).
+/// * `symbol`s, annotations and field names are equivalent if they have the same text. Symbols
+///   with unknown text are equivalent if they were imported from the same position in the same
+///   shared symbol table, and are never equivalent to a symbol with text. Symbols that have
+///   neither text nor an import source are all equivalent to `$0`.
+/// * `list`s and `sexp`s are equivalent if their children are pairwise equivalent.
+/// * `struct`s are equivalent if their fields are equivalent irrespective of order. Repeated
+///   field names are permitted, but each field in one struct must be matched by a distinct field
+///   in the other.
+/// * In all cases, both values must have equivalent annotations in the same order.
+///
+/// ## Usage
+/// ```
+/// # use ion_rs::IonEq;
+/// # use ion_rs::types::decimal::Decimal;
+/// # use ion_rs::value::Builder;
+/// # use ion_rs::value::owned::OwnedElement;
+/// let one_point_zero = Decimal::new(10, -1);
+/// let one_point_zero_zero = Decimal::new(100, -2);
+/// assert_eq!(one_point_zero, one_point_zero_zero);
+/// assert!(!one_point_zero.ion_eq(&one_point_zero_zero));
+///
+/// let nan1 = OwnedElement::new_f64(f64::NAN);
+/// let nan2 = OwnedElement::new_f64(f64::NAN);
+/// assert_ne!(nan1, nan2);
+/// assert!(nan1.ion_eq(&nan2));
+/// ```
+pub trait IonEq {
+    /// Returns `true` if `self` and `other` are equivalent according to the Ion data model.
+    fn ion_eq(&self, other: &Self) -> bool;
+}
+
+impl IonEq for f64 {
+    fn ion_eq(&self, other: &Self) -> bool {
+        if self.is_nan() {
+            // All NaNs are equivalent regardless of their payload.
+            return other.is_nan();
+        }
+        // Comparing the bits distinguishes `0.0` from `-0.0`.
+        self.to_bits() == other.to_bits()
+    }
+}
+
+/// Applies [`IonEq`] to a pair of optional values, treating two `None`s as equivalent.
+fn option_ion_eq<T: IonEq + ?Sized>(this: Option<&T>, that: Option<&T>) -> bool {
+    match (this, that) {
+        (Some(this), Some(that)) => this.ion_eq(that),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Tests two [`ImportSource`]s for equivalence.
+fn import_source_eq<I: ImportSource + ?Sized>(this: &I, that: &I) -> bool {
+    this.table() == that.table() && this.sid() == that.sid()
+}
+
+/// Tests two [`SymbolToken`]s for equivalence as defined by the Ion data model.
+pub(crate) fn symbol_token_ion_eq<S: SymbolToken + ?Sized>(this: &S, that: &S) -> bool {
+    match (this.text(), that.text()) {
+        (Some(this_text), Some(that_text)) => this_text == that_text,
+        (None, None) => match (this.source(), that.source()) {
+            (Some(this_source), Some(that_source)) => import_source_eq(this_source, that_source),
+            // Neither token has an import source, so they are both equivalent to `$0`.
+            (None, None) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Tests two [`Sequence`]s for equivalence by comparing their children pairwise.
+fn sequence_ion_eq<E: Element + ?Sized>(this: &E::Sequence, that: &E::Sequence) -> bool {
+    this.len() == that.len()
+        && this
+            .iter()
+            .zip(that.iter())
+            .all(|(this_child, that_child)| element_ion_eq(this_child, that_child))
+}
+
+/// Tests two [`Struct`]s for equivalence. Field order is not significant, but each field in
+/// `this` must be matched by a distinct equivalent field in `that`.
+fn struct_ion_eq<E: Element + ?Sized>(this: &E::Struct, that: &E::Struct) -> bool {
+    let that_fields: Vec<_> = that.iter().collect();
+    let mut matched = vec![false; that_fields.len()];
+    let mut this_field_count = 0;
+    for (this_name, this_value) in this.iter() {
+        this_field_count += 1;
+        let position = that_fields.iter().zip(matched.iter()).position(
+            |((that_name, that_value), already_matched)| {
+                !*already_matched
+                    && symbol_token_ion_eq(this_name, *that_name)
+                    && element_ion_eq(this_value, *that_value)
+            },
+        );
+        match position {
+            Some(index) => matched[index] = true,
+            None => return false,
+        }
+    }
+    this_field_count == that_fields.len()
+}
+
+/// Tests two [`Element`]s for equivalence as defined by the Ion data model.
+pub(crate) fn element_ion_eq<E: Element + ?Sized>(this: &E, that: &E) -> bool {
+    if this.ion_type() != that.ion_type() || this.is_null() != that.is_null() {
+        return false;
+    }
+
+    let mut this_annotations = this.annotations();
+    let mut that_annotations = that.annotations();
+    loop {
+        match (this_annotations.next(), that_annotations.next()) {
+            (Some(this_token), Some(that_token)) => {
+                if !symbol_token_ion_eq(this_token, that_token) {
+                    return false;
+                }
+            }
+            (None, None) => break,
+            _ => return false,
+        }
+    }
+
+    if this.is_null() {
+        // We've already confirmed that both nulls have the same type.
+        return true;
+    }
+
+    match this.ion_type() {
+        IonType::Null => true,
+        IonType::Boolean => this.as_bool() == that.as_bool(),
+        IonType::Integer => option_ion_eq(this.as_any_int(), that.as_any_int()),
+        IonType::Float => option_ion_eq(this.as_f64().as_ref(), that.as_f64().as_ref()),
+        IonType::Decimal => option_ion_eq(this.as_decimal(), that.as_decimal()),
+        IonType::Timestamp => option_ion_eq(this.as_timestamp(), that.as_timestamp()),
+        IonType::Symbol => match (this.as_sym(), that.as_sym()) {
+            (Some(this_token), Some(that_token)) => symbol_token_ion_eq(this_token, that_token),
+            _ => false,
+        },
+        IonType::String => this.as_str() == that.as_str(),
+        IonType::Clob | IonType::Blob => this.as_bytes() == that.as_bytes(),
+        IonType::List | IonType::SExpression => match (this.as_sequence(), that.as_sequence()) {
+            (Some(this_seq), Some(that_seq)) => sequence_ion_eq::<E>(this_seq, that_seq),
+            _ => false,
+        },
+        IonType::Struct => match (this.as_struct(), that.as_struct()) {
+            (Some(this_struct), Some(that_struct)) => struct_ion_eq::<E>(this_struct, that_struct),
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod ion_eq_tests {
+    use super::*;
+    use crate::types::decimal::Decimal;
+    use crate::types::timestamp::Timestamp;
+    use crate::value::borrowed::{BorrowedElement, BorrowedSymbolToken, BorrowedValue};
+    use crate::value::owned::{OwnedElement, OwnedSymbolToken};
+    use crate::value::reader::{element_reader, ElementReader};
+    use crate::value::{AnyInt, Builder};
+    use num_bigint::BigInt;
+    use rstest::*;
+
+    fn read(ion: &str) -> OwnedElement {
+        element_reader().read_one(ion.as_bytes()).unwrap()
+    }
+
+    #[rstest(
+        ion1,
+        ion2,
+        case::null("null", "null.null"),
+        case::typed_null("null.int", "null.int"),
+        case::int("5", "5"),
+        case::big_int("18446744073709551616", "18446744073709551616"),
+        case::float("1.5e0", "15e-1"),
+        case::nan("nan", "nan"),
+        case::negative_zero_float("-0e0", "-0e0"),
+        case::decimal("1.0", "10d-1"),
+        case::timestamp("2020-01-01T00:00Z", "2020-01-01T00:00+00:00"),
+        case::timestamp_offsets("2020-01-01T05:00+05:00", "2020-01-01T05:00+05:00"),
+        case::timestamp_fractional("2020-01-01T00:00:00.120Z", "2020-01-01T00:00:00.120Z"),
+        case::symbol("foo", "'foo'"),
+        case::string("\"foo\"", "'''foo'''"),
+        case::blob("{{aGVsbG8=}}", "{{ aGVs bG8= }}"),
+        case::list("[1, a, \"b\"]", "[1, a, \"b\"]"),
+        case::sexp("(+ 1 2)", "(+ 1 2)"),
+        case::annotations("a::b::5", "'a'::'b'::5"),
+        case::struct_field_order("{a: 1, b: 2}", "{b: 2, a: 1}"),
+        case::struct_repeated_fields("{a: 1, a: 2, a: 1}", "{a: 2, a: 1, a: 1}"),
+        case::nested("{a: [{b: 1.0}, 2]}", "{a: [{b: 1.0}, 2]}")
+    )]
+    fn ion_eq(ion1: &str, ion2: &str) {
+        let (elem1, elem2) = (read(ion1), read(ion2));
+        assert!(
+            elem1.ion_eq(&elem2),
+            "{:?} should be equivalent to {:?}",
+            elem1,
+            elem2
+        );
+        assert!(
+            elem2.ion_eq(&elem1),
+            "{:?} should be equivalent to {:?}",
+            elem2,
+            elem1
+        );
+    }
+
+    #[rstest(
+        ion1,
+        ion2,
+        case::typed_nulls("null.int", "null.string"),
+        case::null_and_value("null.int", "0"),
+        case::int("5", "6"),
+        case::int_and_float("5", "5e0"),
+        case::negative_zero_float("-0e0", "0e0"),
+        case::decimal_precision("1.0", "1.00"),
+        case::decimal_zero_exponent("0.", "0.0"),
+        case::timestamp_precision("2020T", "2020-01-01T"),
+        case::timestamp_seconds("2020-01-01T00:00Z", "2020-01-01T00:00:00Z"),
+        case::timestamp_unknown_offset("2020-01-01T00:00Z", "2020-01-01T00:00-00:00"),
+        case::timestamp_same_instant("2020-01-01T00:00Z", "2020-01-01T05:00+05:00"),
+        case::timestamp_fractional_precision("2020-01-01T00:00:00.1Z", "2020-01-01T00:00:00.10Z"),
+        case::timestamp_fractional_value("2020-01-01T00:00:00.012Z", "2020-01-01T00:00:00.120Z"),
+        case::symbol_and_string("foo", "\"foo\""),
+        case::blob_and_clob("{{aGVsbG8=}}", "{{\"hello\"}}"),
+        case::list_and_sexp("[1, 2]", "(1 2)"),
+        case::list_order("[1, 2]", "[2, 1]"),
+        case::list_length("[1, 2]", "[1, 2, 3]"),
+        case::nested_decimal("[1.0]", "[1.00]"),
+        case::annotations("a::5", "5"),
+        case::annotation_order("a::b::5", "b::a::5"),
+        case::struct_field_count("{a: 1}", "{a: 1, a: 1}"),
+        case::struct_repeated_fields("{a: 1, a: 2, a: 1}", "{a: 1, a: 2, a: 2}"),
+        case::struct_field_value("{a: 1.0}", "{a: 1.00}")
+    )]
+    fn not_ion_eq(ion1: &str, ion2: &str) {
+        let (elem1, elem2) = (read(ion1), read(ion2));
+        assert!(
+            !elem1.ion_eq(&elem2),
+            "{:?} should not be equivalent to {:?}",
+            elem1,
+            elem2
+        );
+        assert!(
+            !elem2.ion_eq(&elem1),
+            "{:?} should not be equivalent to {:?}",
+            elem2,
+            elem1
+        );
+    }
+
+    #[test]
+    fn partial_eq_differs_from_ion_eq() {
+        let (nan1, nan2) = (read("nan"), read("nan"));
+        assert_ne!(nan1, nan2);
+        assert!(nan1.ion_eq(&nan2));
+
+        let (zero, negative_zero) = (read("0e0"), read("-0e0"));
+        assert_eq!(zero, negative_zero);
+        assert!(!zero.ion_eq(&negative_zero));
+
+        let (one_point_zero, one_point_zero_zero) = (read("1.0"), read("1.00"));
+        assert_eq!(one_point_zero, one_point_zero_zero);
+        assert!(!one_point_zero.ion_eq(&one_point_zero_zero));
+    }
+
+    #[test]
+    fn any_int_ion_eq() {
+        assert!(AnyInt::I64(5).ion_eq(&AnyInt::BigInt(BigInt::from(5))));
+        assert!(AnyInt::BigInt(BigInt::from(-5)).ion_eq(&AnyInt::I64(-5)));
+        assert!(!AnyInt::I64(5).ion_eq(&AnyInt::BigInt(BigInt::from(6))));
+    }
+
+    #[test]
+    fn decimal_and_timestamp_ion_eq() {
+        assert!(Decimal::new(10, -1).ion_eq(&Decimal::new(10, -1)));
+        assert!(!Decimal::new(10, -1).ion_eq(&Decimal::new(1, 0)));
+        assert!(!Decimal::negative_zero().ion_eq(&Decimal::new(0, 0)));
+
+        let millis = Timestamp::with_ymd_hms_millis(2021, 2, 3, 4, 5, 6, 70)
+            .build_at_offset(0)
+            .unwrap();
+        assert!(millis.ion_eq(&millis));
+        let seconds = Timestamp::with_ymd_hms(2021, 2, 3, 4, 5, 6)
+            .build_at_offset(0)
+            .unwrap();
+        assert!(!millis.ion_eq(&seconds));
+    }
+
+    #[test]
+    fn symbol_token_equivalence() {
+        let text = OwnedSymbolToken::text_token("foo");
+        let unknown_text = OwnedSymbolToken::local_sid_token(10).with_source("table", 1);
+        let text_and_source = OwnedSymbolToken::text_token("foo").with_source("table", 1);
+        assert!(symbol_token_ion_eq(&text, &text_and_source));
+        assert!(!symbol_token_ion_eq(&unknown_text, &text_and_source));
+        assert!(!symbol_token_ion_eq(&text, &unknown_text));
+        assert!(symbol_token_ion_eq(
+            &unknown_text,
+            &OwnedSymbolToken::local_sid_token(20).with_source("table", 1)
+        ));
+        assert!(!symbol_token_ion_eq(
+            &unknown_text,
+            &OwnedSymbolToken::local_sid_token(10).with_source("table", 2)
+        ));
+        // Tokens with neither text nor a source are all equivalent to `$0`.
+        assert!(symbol_token_ion_eq(
+            &OwnedSymbolToken::local_sid_token(0),
+            &OwnedSymbolToken::local_sid_token(200)
+        ));
+        assert!(!symbol_token_ion_eq(
+            &OwnedSymbolToken::local_sid_token(0),
+            &text
+        ));
+    }
+
+    #[test]
+    fn borrowed_ion_eq() {
+        let annotations = || vec![BorrowedSymbolToken::text_token("a")];
+        let nan = BorrowedElement::new(annotations(), BorrowedValue::Float(f64::NAN));
+        assert!(nan.ion_eq(&BorrowedElement::new(
+            annotations(),
+            BorrowedValue::Float(f64::NAN)
+        )));
+        assert!(!nan.ion_eq(&BorrowedElement::new(
+            vec![],
+            BorrowedValue::Float(f64::NAN)
+        )));
+
+        let one_point_zero = BorrowedElement::new_decimal(Decimal::new(10, -1));
+        let one_point_zero_zero = BorrowedElement::new_decimal(Decimal::new(100, -2));
+        assert_eq!(one_point_zero, one_point_zero_zero);
+        assert!(!one_point_zero.ion_eq(&one_point_zero_zero));
+    }
+}
@@ -0,0 +1,359 @@
+//! Provides [`IonOrd`], a total ordering over Ion values.
+//!
+//! The Ion data model does not define an ordering for its values, but sorting and deduplicating
+//! values (e.g. in an index or when producing deterministic output) requires one. [`IonOrd`]
+//! defines a canonical ordering that is consistent with [`IonEq`](crate::IonEq): values that are
+//! equivalent according to the data model are ordered as [`Ordering::Equal`].
+
+use crate::value::{Element, ImportSource, Sequence, Struct, SymbolToken};
+use crate::IonType;
+use std::cmp::Ordering;
+
+/// A total ordering over Ion values.
+///
+/// Elements are ordered first by their Ion type, then by their value, and finally by their
+/// annotations. The types are ordered as they are declared in [`IonType`](crate::IonType):
+/// `null` < `bool` < `int` < `float` < `decimal` < `timestamp` < `symbol` < `string` < `clob` <
+/// `blob` < `list` < `sexp` < `struct`. Within each type, a typed `null` sorts before all other
+/// values of that type. Values are then ordered as follows:
+/// * `bool`s: `false` < `true`.
+/// * `int`s: numerically, regardless of how they are represented in memory.
+/// * `float`s: numerically, with `-0e0` < `0e0` and `nan` after every other value (including
+///   `+inf`). All `nan`s are ordered as equal.
+/// * `decimal`s: numerically, with `-0.` < `0.`. Decimals with the same value are ordered by
+///   precision, less precise first: `1.0` < `1.00`.
+/// * `timestamp`s: by the point in time they represent, then by precision (less precise first),
+///   then by offset (unknown first, then from west to east), then by the number of digits in
+///   their fractional seconds (fewer first).
+/// * `symbol`s: symbols with unknown text come first, ordered by their import source (table name,
+///   then position) with `$0` first. Symbols with text follow, ordered by their text.
+/// * `string`s: lexicographically by Unicode code point.
+/// * `clob`s and `blob`s: lexicographically by byte.
+/// * `list`s and `sexp`s: lexicographically by child; a sequence that is a prefix of another
+///   sorts first.
+/// * `struct`s: each struct's fields are sorted by field name and then by value. The sorted
+///   fields are then compared lexicographically, so field order does not affect the result.
+///
+/// Annotations, which are compared only when the values are otherwise equal, are compared
+/// lexicographically using the same rules as `symbol`s. Unannotated values sort first.
+///
+/// Two values are ordered as [`Ordering::Equal`] if and only if they are equivalent according to
+/// [`IonEq`](crate::IonEq).
+///
+/// ## Usage
+/// ```
+/// # use ion_rs::{IonEq, IonOrd};
+/// # use ion_rs::value::Builder;
+/// # use ion_rs::value::owned::OwnedElement;
+/// let mut elements = vec![
+///     OwnedElement::new_string("hello"),
+///     OwnedElement::new_f64(f64::NAN),
+///     OwnedElement::new_i64(5),
+///     OwnedElement::new_f64(0.0),
+///     OwnedElement::new_f64(-0.0),
+/// ];
+/// elements.sort_by(|e1, e2| e1.ion_cmp(e2));
+///
+/// let expected = vec![
+///     OwnedElement::new_i64(5),
+///     OwnedElement::new_f64(-0.0),
+///     OwnedElement::new_f64(0.0),
+///     OwnedElement::new_f64(f64::NAN),
+///     OwnedElement::new_string("hello"),
+/// ];
+/// assert!(elements.iter().zip(expected.iter()).all(|(e1, e2)| e1.ion_eq(e2)));
+/// ```
+pub trait IonOrd {
+    /// Returns an [`Ordering`] between `self` and `other` according to the canonical Ion ordering.
+    fn ion_cmp(&self, other: &Self) -> Ordering;
+}
+
+impl IonOrd for f64 {
+    fn ion_cmp(&self, other: &Self) -> Ordering {
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Greater,
+            (false, true) => return Ordering::Less,
+            (false, false) => {}
+        }
+        match self.partial_cmp(other) {
+            // `-0.0` and `0.0` are numerically equal, so they are ordered by their signs.
+            Some(Ordering::Equal) | None => self.is_sign_positive().cmp(&other.is_sign_positive()),
+            Some(ordering) => ordering,
+        }
+    }
+}
+
+/// Applies [`IonOrd`] to a pair of optional values, ordering `None` first.
+fn option_ion_cmp<T: IonOrd + ?Sized>(this: Option<&T>, that: Option<&T>) -> Ordering {
+    match (this, that) {
+        (Some(this), Some(that)) => this.ion_cmp(that),
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+    }
+}
+
+/// Orders two [`SymbolToken`]s. Tokens with unknown text come first, ordered by their import
+/// source (tokens without a source first). Tokens with text follow, ordered by their text.
+pub(crate) fn symbol_token_ion_cmp<S: SymbolToken + ?Sized>(this: &S, that: &S) -> Ordering {
+    match (this.text(), that.text()) {
+        (Some(this_text), Some(that_text)) => this_text.cmp(that_text),
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (None, None) => {
+            let this_source = this.source().map(|source| (source.table(), source.sid()));
+            let that_source = that.source().map(|source| (source.table(), source.sid()));
+            this_source.cmp(&that_source)
+        }
+    }
+}
+
+/// Compares two iterators lexicographically using the provided comparison function.
+fn iter_ion_cmp<T, I, F>(mut this: I, mut that: I, mut compare: F) -> Ordering
+where
+    I: Iterator<Item = T>,
+    F: FnMut(T, T) -> Ordering,
+{
+    loop {
+        match (this.next(), that.next()) {
+            (Some(this_item), Some(that_item)) => {
+                let ordering = compare(this_item, that_item);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+        }
+    }
+}
+
+/// Orders two [`Struct`]s by sorting their fields and then comparing them lexicographically.
+fn struct_ion_cmp<E: Element + ?Sized>(this: &E::Struct, that: &E::Struct) -> Ordering {
+    fn field_ion_cmp<E: Element + ?Sized>(
+        (this_name, this_value): (&E::SymbolToken, &E),
+        (that_name, that_value): (&E::SymbolToken, &E),
+    ) -> Ordering {
+        symbol_token_ion_cmp(this_name, that_name)
+            .then_with(|| element_ion_cmp(this_value, that_value))
+    }
+
+    let mut this_fields: Vec<_> = this.iter().collect();
+    let mut that_fields: Vec<_> = that.iter().collect();
+    this_fields.sort_by(|f1, f2| field_ion_cmp::<E>(*f1, *f2));
+    that_fields.sort_by(|f1, f2| field_ion_cmp::<E>(*f1, *f2));
+    iter_ion_cmp(
+        this_fields.into_iter(),
+        that_fields.into_iter(),
+        field_ion_cmp::<E>,
+    )
+}
+
+/// Orders two [`Element`]s according to the canonical Ion ordering.
+pub(crate) fn element_ion_cmp<E: Element + ?Sized>(this: &E, that: &E) -> Ordering {
+    let type_cmp = this.ion_type().cmp(&that.ion_type());
+    if type_cmp != Ordering::Equal {
+        return type_cmp;
+    }
+
+    // A typed null sorts before every other value of the same type.
+    let value_cmp = match (this.is_null(), that.is_null()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => value_ion_cmp(this, that),
+    };
+    if value_cmp != Ordering::Equal {
+        return value_cmp;
+    }
+
+    iter_ion_cmp(this.annotations(), that.annotations(), |t1, t2| {
+        symbol_token_ion_cmp(t1, t2)
+    })
+}
+
+/// Orders the values of two non-null [`Element`]s that have the same Ion type.
+fn value_ion_cmp<E: Element + ?Sized>(this: &E, that: &E) -> Ordering {
+    match this.ion_type() {
+        IonType::Null => Ordering::Equal,
+        IonType::Boolean => this.as_bool().cmp(&that.as_bool()),
+        IonType::Integer => option_ion_cmp(this.as_any_int(), that.as_any_int()),
+        IonType::Float => option_ion_cmp(this.as_f64().as_ref(), that.as_f64().as_ref()),
+        IonType::Decimal => option_ion_cmp(this.as_decimal(), that.as_decimal()),
+        IonType::Timestamp => option_ion_cmp(this.as_timestamp(), that.as_timestamp()),
+        IonType::Symbol => match (this.as_sym(), that.as_sym()) {
+            (Some(this_token), Some(that_token)) => symbol_token_ion_cmp(this_token, that_token),
+            (this_token, that_token) => this_token.is_some().cmp(&that_token.is_some()),
+        },
+        IonType::String => this.as_str().cmp(&that.as_str()),
+        IonType::Clob | IonType::Blob => this.as_bytes().cmp(&that.as_bytes()),
+        IonType::List | IonType::SExpression => match (this.as_sequence(), that.as_sequence()) {
+            (Some(this_seq), Some(that_seq)) => {
+                iter_ion_cmp(this_seq.iter(), that_seq.iter(), |c1, c2| {
+                    element_ion_cmp(c1, c2)
+                })
+            }
+            (this_seq, that_seq) => this_seq.is_some().cmp(&that_seq.is_some()),
+        },
+        IonType::Struct => match (this.as_struct(), that.as_struct()) {
+            (Some(this_struct), Some(that_struct)) => struct_ion_cmp::<E>(this_struct, that_struct),
+            (this_struct, that_struct) => this_struct.is_some().cmp(&that_struct.is_some()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod ion_ord_tests {
+    use super::*;
+    use crate::ion_eq::{symbol_token_ion_eq, IonEq};
+    use crate::types::timestamp::Timestamp;
+    use crate::value::borrowed::{BorrowedElement, BorrowedValue};
+    use crate::value::owned::{OwnedElement, OwnedSymbolToken};
+    use crate::value::reader::{element_reader, ElementReader};
+    use crate::value::{AnyInt, Builder};
+    use num_bigint::BigInt;
+    use rstest::*;
+
+    fn read_all(ion: &str) -> Vec<OwnedElement> {
+        element_reader().read_all(ion.as_bytes()).unwrap()
+    }
+
+    /// Asserts that the values in `ion` are in strictly ascending order and that the ordering
+    /// agrees with [`IonEq`].
+    fn assert_ascending(ion: &str) {
+        let elements = read_all(ion);
+        for (index, e1) in elements.iter().enumerate() {
+            for (other_index, e2) in elements.iter().enumerate() {
+                let ordering = e1.ion_cmp(e2);
+                assert_eq!(index.cmp(&other_index), ordering, "{:?} vs {:?}", e1, e2);
+                assert_eq!(ordering == Ordering::Equal, e1.ion_eq(e2));
+            }
+        }
+    }
+
+    #[rstest(
+        ion,
+        case::types("null true 1 1e0 1.0 2020T a \"a\" {{\"a\"}} {{YQ==}} [a] (a) {a: a}"),
+        case::nulls("null null.bool false null.int 0 null.struct {}"),
+        case::bools("false true"),
+        case::ints("-18446744073709551616 -5 0 5 18446744073709551616"),
+        case::floats("-inf -1e0 -0e0 0e0 1e-10 1e0 +inf nan"),
+        case::decimals("-1.0 0. 0.0 0.00 1.0 1.00 1.1 10."),
+        case::timestamps(
+            "2020T 2020-01-01T 2020-01-01T00:00-00:00 2020-01-01T00:00Z 2020-01-01T01:00+01:00 \
+             2020-01-01T00:00:00Z 2020-01-01T00:00:00.0Z 2020-01-01T00:00:00.00Z \
+             2020-01-01T00:00:00.1Z 2020-01-01T00:00:01Z 2020-01-01T03:30+02:00 2020-02T 2021T"
+        ),
+        case::symbols("'' a aa b"),
+        case::strings("\"\" \"A\" \"a\" \"aa\" \"b\" \"\\u00e9\""),
+        case::lobs("{{\"\"}} {{\"a\"}} {{\"ab\"}} {{\"b\"}}"),
+        case::lists("[] [1] [1, 1] [1, 2] [2]"),
+        case::sexps("() (a) (a b) (b)"),
+        case::structs("{} {a: 1} {a: 1, a: 1} {a: 1, a: 2} {a: 1, b: 1} {a: 2} {b: 1}"),
+        case::annotations("5 a::5 a::a::5 a::b::5 b::5 6")
+    )]
+    fn ion_cmp(ion: &str) {
+        assert_ascending(ion);
+    }
+
+    #[rstest(
+        ion1,
+        ion2,
+        case::struct_field_order("{a: 1, b: 2}", "{b: 2, a: 1}"),
+        case::struct_repeated_fields("{a: 1, a: 2, a: 1}", "{a: 2, a: 1, a: 1}"),
+        case::int_representations("5", "0x5"),
+        case::nans("nan", "nan"),
+        case::timestamp_offsets("2020-01-01T00:00Z", "2020-01-01T00:00+00:00")
+    )]
+    fn ion_cmp_equal(ion1: &str, ion2: &str) {
+        let elements = read_all(&format!("{} {}", ion1, ion2));
+        assert_eq!(Ordering::Equal, elements[0].ion_cmp(&elements[1]));
+        assert_eq!(Ordering::Equal, elements[1].ion_cmp(&elements[0]));
+    }
+
+    #[test]
+    fn sort_and_dedup() {
+        let mut elements = read_all("{b: 2, a: 1} 1.00 nan 1.0 {a: 1, b: 2} nan 1.0 a::1.0");
+        elements.sort_by(|e1, e2| e1.ion_cmp(e2));
+        elements.dedup_by(|e1, e2| e1.ion_eq(e2));
+        let expected = read_all("nan 1.0 a::1.0 1.00 {a: 1, b: 2}");
+        assert_eq!(expected.len(), elements.len());
+        for (actual, expected) in elements.iter().zip(expected.iter()) {
+            assert!(actual.ion_eq(expected), "{:?} vs {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn any_int_ion_cmp() {
+        let big = |value: i64| AnyInt::BigInt(BigInt::from(value));
+        assert_eq!(Ordering::Equal, AnyInt::I64(5).ion_cmp(&big(5)));
+        assert_eq!(Ordering::Less, big(-6).ion_cmp(&AnyInt::I64(-5)));
+        assert_eq!(Ordering::Greater, AnyInt::I64(7).ion_cmp(&big(6)));
+    }
+
+    #[test]
+    fn timestamp_ion_cmp() {
+        let millis = Timestamp::with_ymd_hms_millis(2021, 2, 3, 4, 5, 6, 70)
+            .build_at_offset(0)
+            .unwrap();
+        let micros = Timestamp::with_ymd_hms(2021, 2, 3, 4, 5, 6)
+            .with_microseconds(70_000)
+            .build_at_offset(0)
+            .unwrap();
+        let seconds = Timestamp::with_ymd_hms(2021, 2, 3, 4, 5, 6)
+            .build_at_offset(0)
+            .unwrap();
+        assert_eq!(Ordering::Less, seconds.ion_cmp(&millis));
+        assert_eq!(Ordering::Less, millis.ion_cmp(&micros));
+        assert_eq!(Ordering::Equal, micros.ion_cmp(&micros));
+    }
+
+    #[test]
+    fn symbol_token_ion_cmp_ordering() {
+        let tokens = vec![
+            OwnedSymbolToken::local_sid_token(0),
+            OwnedSymbolToken::local_sid_token(10).with_source("a", 2),
+            OwnedSymbolToken::local_sid_token(10).with_source("b", 1),
+            OwnedSymbolToken::text_token("a"),
+            OwnedSymbolToken::text_token("a").with_source("z", 1),
+            OwnedSymbolToken::text_token("b"),
+        ];
+        for (index, t1) in tokens.iter().enumerate() {
+            for (other_index, t2) in tokens.iter().enumerate() {
+                let expected = match (index, other_index) {
+                    // Tokens with the same text are ordered as equal regardless of their source.
+                    (3, 4) | (4, 3) => Ordering::Equal,
+                    _ => index.cmp(&other_index),
+                };
+                assert_eq!(
+                    expected,
+                    symbol_token_ion_cmp(t1, t2),
+                    "{:?} vs {:?}",
+                    t1,
+                    t2
+                );
+                assert_eq!(
+                    expected == Ordering::Equal,
+                    symbol_token_ion_eq(t1, t2),
+                    "{:?} vs {:?}",
+                    t1,
+                    t2
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn borrowed_ion_cmp() {
+        let nan = BorrowedElement::from(BorrowedValue::Float(f64::NAN));
+        let inf = BorrowedElement::new_f64(f64::INFINITY);
+        assert_eq!(Ordering::Greater, nan.ion_cmp(&inf));
+        assert_eq!(Ordering::Equal, nan.ion_cmp(&nan));
+        assert_eq!(
+            Ordering::Less,
+            BorrowedElement::new_null(IonType::Float).ion_cmp(&inf)
+        );
+    }
+}
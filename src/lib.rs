@@ -18,19 +18,37 @@ pub mod result;
 pub mod binary;
 pub mod cursor;
 pub mod data_source;
+pub mod ion_eq;
+pub mod ion_ord;
 pub mod text;
 pub mod types;
 pub mod value;
+pub mod writer;
 
+#[cfg(feature = "serde")]
+pub mod de;
+#[cfg(feature = "serde")]
+pub mod ser;
+
+mod catalog;
 pub mod constants;
 mod reader;
 mod symbol_table;
 mod system_event_handler;
 
 pub use binary::cursor::BinaryIonCursor;
+pub use catalog::{Catalog, MapCatalog, SharedSymbolTable};
 pub use cursor::Cursor;
 pub use data_source::IonDataSource;
+#[cfg(feature = "serde")]
+pub use de::{from_reader, from_slice};
+pub use ion_eq::IonEq;
+pub use ion_ord::IonOrd;
 pub use reader::Reader;
+#[cfg(feature = "serde")]
+pub use ser::{to_vec, to_writer};
 pub use symbol_table::SymbolTable;
 pub use system_event_handler::SystemEventHandler;
+pub use text::cursor::TextIonCursor;
 pub use types::IonType;
+pub use writer::IonWriter;
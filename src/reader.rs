@@ -5,15 +5,26 @@ use std::ops::Range;
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, FixedOffset};
 use delegate::delegate;
+use num_bigint::BigInt;
 
-use crate::constants::v1_0::system_symbol_ids;
+use crate::catalog::{Catalog, MapCatalog};
+use crate::constants::v1_0::{system_symbol_ids, SYSTEM_SYMBOLS};
 use crate::cursor::StreamItem::*;
-use crate::result::IonResult;
+use crate::cursor::{IntegerSize, RawSymbolToken};
+use crate::result::{decoding_error, IonResult};
 use crate::symbol_table::SymbolTable;
 use crate::system_event_handler::SystemEventHandler;
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
+use crate::value::AnyInt;
 use crate::{BinaryIonCursor, Cursor, IonType};
 
+/// The largest number of symbol IDs that the imports of a single local symbol table may define.
+/// An import's `max_id` is read from the stream, so without a limit a malformed or malicious
+/// import could make the Reader try to allocate a slot for every possible symbol ID.
+const MAX_IMPORTED_SYMBOLS: usize = 1 << 20;
+
 /// A streaming Ion reader that resolves symbol IDs into the appropriate text.
 ///
 /// Reader itself is format-agnostic; all format-specific logic is handled by the
@@ -21,6 +32,7 @@ use crate::{BinaryIonCursor, Cursor, IonType};
 pub struct Reader<C: Cursor> {
     cursor: C,
     symbol_table: SymbolTable,
+    catalog: Box<dyn Catalog>,
     system_event_handler: Option<Box<dyn SystemEventHandler>>,
 }
 
@@ -29,10 +41,20 @@ impl<C: Cursor> Reader<C> {
         Reader {
             cursor,
             symbol_table: SymbolTable::new(),
+            catalog: Box::new(MapCatalog::new()),
             system_event_handler: None,
         }
     }
 
+    /// Allows the user to specify the Catalog of shared symbol tables that will be used to resolve
+    /// the imports of local symbol tables. By default, the Reader uses an empty catalog.
+    pub fn set_catalog<T>(&mut self, catalog: T)
+    where
+        T: 'static + Catalog,
+    {
+        self.catalog = Box::new(catalog);
+    }
+
     /// Allows the user to specify an implementation of SymbolTableEventHandler to respond
     /// to otherwise internal events like symbol table imports and appends.
     // TODO: Boxing this type means that it is impossible to retrieve from the Reader later.
@@ -56,8 +78,7 @@ impl<C: Cursor> Reader<C> {
                     self.invoke_on_symbol_table_reset_handler();
                 }
                 Some(Value(IonType::Struct, false)) => {
-                    if let [system_symbol_ids::ION_SYMBOL_TABLE, ..] = self.cursor.annotation_ids()
-                    {
+                    if self.cursor.depth() == 0 && self.is_symbol_table() {
                         self.read_symbol_table()?;
                     } else {
                         return Ok(Some((IonType::Struct, false)));
@@ -69,45 +90,90 @@ impl<C: Cursor> Reader<C> {
         }
     }
 
+    /// Advances the cursor to the next value without processing system-level directives. Ion
+    /// version markers and local symbol tables are reported as errors, which makes this suitable
+    /// for reading formats like JSON that have no system values.
+    pub(crate) fn next_without_system_values(&mut self) -> IonResult<Option<(IonType, bool)>> {
+        match self.cursor.next()? {
+            Some(VersionMarker(major, minor)) => decoding_error(format!(
+                "Found an Ion version marker for Ion {}.{} where system values are not allowed.",
+                major, minor
+            )),
+            Some(Value(IonType::Struct, false))
+                if self.cursor.depth() == 0 && self.is_symbol_table() =>
+            {
+                decoding_error("Found a local symbol table where system values are not allowed.")
+            }
+            Some(Value(ion_type, is_null)) => Ok(Some((ion_type, is_null))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns true if the current value's first annotation is `$ion_symbol_table`, whether it
+    /// was encoded as a symbol ID (binary Ion, or `$3` in text Ion) or as text.
+    fn is_symbol_table(&self) -> bool {
+        match self.cursor.raw_annotations().next() {
+            Some(annotation) => {
+                system_symbol_id(annotation) == Some(system_symbol_ids::ION_SYMBOL_TABLE)
+            }
+            None => false,
+        }
+    }
+
     fn read_symbol_table(&mut self) -> IonResult<()> {
         self.cursor.step_in()?;
 
         let mut is_append = false;
+        let mut imported_symbols = vec![];
         let mut new_symbols = vec![];
+        let mut has_imports = false;
+        let mut has_symbols = false;
 
         while let Some(Value(ion_type, is_null)) = self.cursor.next()? {
-            let field_id = self
-                .cursor
-                .field_id()
-                .expect("No field ID found inside $ion_symbol_table struct.");
+            let field_id = self.cursor.raw_field_name().and_then(system_symbol_id);
+            match field_id {
+                Some(system_symbol_ids::IMPORTS) if has_imports => {
+                    return decoding_error("Found a symbol table with multiple 'imports' fields.");
+                }
+                Some(system_symbol_ids::SYMBOLS) if has_symbols => {
+                    return decoding_error("Found a symbol table with multiple 'symbols' fields.");
+                }
+                Some(system_symbol_ids::IMPORTS) => has_imports = true,
+                Some(system_symbol_ids::SYMBOLS) => has_symbols = true,
+                _ => {}
+            }
             match (field_id, ion_type, is_null) {
-                // TODO: This implementation only supports local symbol table imports and appends.
-                (system_symbol_ids::IMPORTS, IonType::Symbol, false) => {
-                    if self.cursor.read_symbol_id()?.unwrap() != 3 {
-                        unimplemented!("Can't handle non-$ion_symbol_table imports value.");
-                    }
-                    is_append = true;
+                (Some(system_symbol_ids::IMPORTS), IonType::Symbol, false) => {
+                    // Any symbol other than `$ion_symbol_table` is ignored, as if there were no
+                    // imports.
+                    let imports = self.cursor.read_raw_symbol()?.and_then(system_symbol_id);
+                    is_append = imports == Some(system_symbol_ids::ION_SYMBOL_TABLE);
                 }
-                (system_symbol_ids::SYMBOLS, IonType::List, false) => {
+                (Some(system_symbol_ids::IMPORTS), IonType::List, false) => {
+                    imported_symbols = self.read_imports()?;
+                }
+                (Some(system_symbol_ids::SYMBOLS), IonType::List, false) => {
                     self.cursor.step_in()?;
-                    while let Some(Value(IonType::String, false)) = self.cursor.next()? {
-                        let text = self.cursor.read_string()?.unwrap();
+                    while let Some(Value(ion_type, is_null)) = self.cursor.next()? {
+                        // Entries that are not strings still occupy a symbol ID, but their text
+                        // is unknown.
+                        let text = match (ion_type, is_null) {
+                            (IonType::String, false) => self.cursor.read_string()?,
+                            _ => None,
+                        };
                         new_symbols.push(text);
                     }
                     self.cursor.step_out()?;
                 }
-                something_else => {
-                    unimplemented!("No support for {:?}", something_else);
-                }
+                // Unrecognized fields and fields with unexpected types are ignored.
+                _ => {}
             }
         }
 
         if is_append {
             // We're adding new symbols to the end of the symbol table.
             let new_ids_start = self.symbol_table.len();
-            for new_symbol in new_symbols.drain(..) {
-                let _id = self.symbol_table.intern(new_symbol);
-            }
+            self.add_symbols(new_symbols);
             // If a symtab event handler is defined, pass it an immutable reference to the symbol
             // table and the ID of the first new symbol that was added.
             self.invoke_on_append_handler(new_ids_start);
@@ -115,9 +181,8 @@ impl<C: Cursor> Reader<C> {
             // The symbol table has been set by defining new symbols without importing the current
             // symbol table.
             self.symbol_table.reset();
-            for new_symbol in new_symbols.drain(..) {
-                let _id = self.symbol_table.intern(new_symbol);
-            }
+            self.add_symbols(imported_symbols);
+            self.add_symbols(new_symbols);
             // If a symtab event handler is defined, pass it an immutable reference to the symbol
             // table so it can be inspected.
             self.invoke_on_symbol_table_reset_handler();
@@ -127,6 +192,110 @@ impl<C: Cursor> Reader<C> {
         Ok(())
     }
 
+    // Adds the provided symbols to the end of the symbol table. Symbols whose text is unknown
+    // are added as placeholders.
+    fn add_symbols(&mut self, symbols: Vec<Option<String>>) {
+        for symbol in symbols {
+            let _id = match symbol {
+                Some(text) => self.symbol_table.add_symbol(text),
+                None => self.symbol_table.add_placeholder(),
+            };
+        }
+    }
+
+    /// Reads the list of shared symbol tables imported by a local symbol table, returning the
+    /// symbols they define in order. Symbols whose text cannot be found in the catalog are None.
+    fn read_imports(&mut self) -> IonResult<Vec<Option<String>>> {
+        let mut imported_symbols = vec![];
+        self.cursor.step_in()?;
+        while let Some(Value(ion_type, is_null)) = self.cursor.next()? {
+            // Entries in the imports list that are not structs are ignored.
+            if ion_type == IonType::Struct && !is_null {
+                self.read_import(&mut imported_symbols)?;
+            }
+        }
+        self.cursor.step_out()?;
+        Ok(imported_symbols)
+    }
+
+    fn read_import(&mut self, imported_symbols: &mut Vec<Option<String>>) -> IonResult<()> {
+        let mut name = None;
+        let mut version = None;
+        let mut max_id = None;
+
+        self.cursor.step_in()?;
+        while let Some(Value(ion_type, is_null)) = self.cursor.next()? {
+            let field_id = self.cursor.raw_field_name().and_then(system_symbol_id);
+            match (field_id, ion_type, is_null) {
+                (Some(system_symbol_ids::NAME), IonType::String, false) => {
+                    name = self.cursor.read_string()?;
+                }
+                (Some(system_symbol_ids::VERSION), IonType::Integer, false) => {
+                    version = self.cursor.read_i64()?;
+                }
+                (Some(system_symbol_ids::MAX_ID), IonType::Integer, false) => {
+                    max_id = self.cursor.read_i64()?;
+                }
+                // Any other fields are ignored.
+                _ => {}
+            }
+        }
+        self.cursor.step_out()?;
+
+        // Imports without a name and imports of the system symbol table are ignored.
+        let name = match name {
+            Some(name) if !name.is_empty() && name != "$ion" => name,
+            _ => return Ok(()),
+        };
+        // A missing or invalid version is treated as version 1.
+        let version = match version {
+            Some(version) if version >= 1 => version as usize,
+            _ => 1,
+        };
+        // A negative max_id is treated as if it were not specified.
+        let max_id = max_id
+            .filter(|max_id| *max_id >= 0)
+            .map(|max_id| max_id as usize);
+
+        let exact_match = self.catalog.get_table_with_version(&name, version);
+        let (table, max_id) = match (exact_match, max_id) {
+            (Some(table), None) => (Some(table), table.symbols().len()),
+            (None, None) => {
+                return decoding_error(format!(
+                    "Imported shared symbol table '{}' version {} is not in the catalog, so its \
+                     import must specify a max_id.",
+                    name, version
+                ))
+            }
+            // If the exact version is not in the catalog, the highest version available is
+            // used instead. If no version is available, every imported symbol has unknown text.
+            (exact_match, Some(max_id)) => (
+                exact_match.or_else(|| self.catalog.get_table(&name)),
+                max_id,
+            ),
+        };
+
+        // Every imported symbol ID occupies a slot in the symbol table, even if its text is
+        // unknown. The max_id comes from the input, so we refuse to allocate an unbounded number
+        // of slots for it.
+        if max_id > MAX_IMPORTED_SYMBOLS - imported_symbols.len() {
+            return decoding_error(format!(
+                "The imports of a local symbol table cannot define more than {} symbols; the \
+                 import of '{}' version {} would bring the total to {}.",
+                MAX_IMPORTED_SYMBOLS,
+                name,
+                version,
+                imported_symbols.len() as u128 + max_id as u128
+            ));
+        }
+
+        let known_symbols = table.map(|table| table.symbols()).unwrap_or(&[]);
+        for index in 0..max_id {
+            imported_symbols.push(known_symbols.get(index).cloned().flatten());
+        }
+        Ok(())
+    }
+
     fn invoke_on_ivm_handler(&mut self, ion_version: (u8, u8)) {
         self.system_event_handler
             .as_mut()
@@ -160,17 +329,36 @@ impl<C: Cursor> Reader<C> {
     }
 
     pub fn field_name(&self) -> Option<&str> {
-        if let Some(id) = self.cursor.field_id() {
-            return self.symbol_table.text_for(id);
+        match self.cursor.raw_field_name()? {
+            RawSymbolToken::SymbolId(id) => self.symbol_table.text_for(id),
+            RawSymbolToken::Text(text) => Some(text),
         }
-        None
     }
 
-    pub fn annotations(&self) -> impl Iterator<Item = &str> {
+    /// Returns an iterator over the text of the current value's annotations. An item is an Err if
+    /// its annotation refers to a symbol ID that is not defined in the current symbol table or
+    /// whose text is unknown.
+    pub fn annotations(&self) -> impl Iterator<Item = IonResult<&str>> {
         self.cursor
-            .annotation_ids()
-            .iter()
-            .map(move |sid| self.symbol_table.text_for(sid.clone()).unwrap())
+            .raw_annotations()
+            .map(move |annotation| match annotation {
+                RawSymbolToken::SymbolId(sid) => text_for_sid(&self.symbol_table, sid),
+                RawSymbolToken::Text(text) => Ok(text),
+            })
+    }
+
+    /// If the current value is a symbol, returns its text; otherwise, returns None. Returns an Err
+    /// if the symbol refers to a symbol ID that is not defined in the current symbol table or
+    /// whose text is unknown.
+    pub fn read_symbol(&mut self) -> IonResult<Option<String>> {
+        let symbol_table = &self.symbol_table;
+        match self.cursor.read_raw_symbol()? {
+            Some(RawSymbolToken::SymbolId(sid)) => {
+                text_for_sid(symbol_table, sid).map(|text| Some(text.to_string()))
+            }
+            Some(RawSymbolToken::Text(text)) => Ok(Some(text.to_string())),
+            None => Ok(None),
+        }
     }
 
     pub fn symbol_table(&self) -> &SymbolTable {
@@ -187,17 +375,25 @@ impl<C: Cursor> Reader<C> {
             pub fn ion_type(&self) -> Option<IonType>;
             pub fn annotation_ids(&self) -> &[SymbolId];
             pub fn field_id(&self) -> Option<SymbolId>;
+            pub fn raw_annotations<'a>(&'a self) -> Box<dyn Iterator<Item = RawSymbolToken<'a>> + 'a>;
+            pub fn raw_field_name(&self) -> Option<RawSymbolToken>;
             pub fn read_null(&mut self) -> IonResult<Option<IonType>>;
             pub fn read_bool(&mut self) -> IonResult<Option<bool>>;
+            pub fn integer_size(&self) -> Option<IntegerSize>;
             pub fn read_i64(&mut self) -> IonResult<Option<i64>>;
+            pub fn read_big_int(&mut self) -> IonResult<Option<BigInt>>;
+            pub fn read_any_int(&mut self) -> IonResult<Option<AnyInt>>;
             pub fn read_f32(&mut self) -> IonResult<Option<f32>>;
             pub fn read_f64(&mut self) -> IonResult<Option<f64>>;
             pub fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
+            pub fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
             pub fn read_string(&mut self) -> IonResult<Option<String>>;
             pub fn read_symbol_id(&mut self) -> IonResult<Option<SymbolId>>;
+            pub fn read_raw_symbol(&mut self) -> IonResult<Option<RawSymbolToken>>;
             pub fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
             pub fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
             pub fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>>;
+            pub fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
             pub fn step_in(&mut self) -> IonResult<()>;
             pub fn step_out(&mut self) -> IonResult<()>;
             pub fn depth(&self) -> usize;
@@ -211,6 +407,33 @@ impl<C: Cursor> Reader<C> {
     }
 }
 
+/// Returns the text associated with the provided symbol ID. Returns an Err if the symbol ID is not
+/// defined in the provided symbol table or if its text is unknown.
+fn text_for_sid(symbol_table: &SymbolTable, sid: SymbolId) -> IonResult<&str> {
+    match symbol_table.text_for(sid) {
+        Some(text) => Ok(text),
+        None if symbol_table.sid_is_valid(sid) => decoding_error(format!(
+            "Symbol ID ${} was imported from a shared symbol table that is not in the \
+             catalog, so its text is unknown.",
+            sid
+        )),
+        None => decoding_error(format!(
+            "Symbol ID ${} is not defined in the current symbol table.",
+            sid
+        )),
+    }
+}
+
+/// Returns the system symbol ID that the provided token refers to, if any. System symbols can be
+/// encoded either as a symbol ID or, in text Ion, as their text (e.g. `$ion_symbol_table`).
+fn system_symbol_id(token: RawSymbolToken) -> Option<SymbolId> {
+    match token {
+        RawSymbolToken::SymbolId(sid) if sid < SYSTEM_SYMBOLS.len() => Some(sid),
+        RawSymbolToken::SymbolId(_) => None,
+        RawSymbolToken::Text(text) => SYSTEM_SYMBOLS.iter().position(|symbol| *symbol == text),
+    }
+}
+
 /// Functionality that is only available if the data source we're reading from is in-memory, like
 /// a Vec<u8> or &[u8].
 impl<T: AsRef<[u8]>> Reader<BinaryIonCursor<io::Cursor<T>>> {
@@ -248,10 +471,11 @@ mod tests {
     use crate::binary::constants::v1_0::IVM;
     use crate::binary::cursor::BinaryIonCursor;
     use crate::cursor::{Cursor, StreamItem::*};
-    use crate::result::IonResult;
+    use crate::result::{IonError, IonResult};
     use crate::system_event_handler::SystemEventHandler;
     use crate::types::IonType;
-    use crate::{Reader, SymbolTable};
+    use crate::{MapCatalog, Reader, SharedSymbolTable, SymbolTable, TextIonCursor};
+    use rstest::*;
 
     type TestDataSource = io::Cursor<Vec<u8>>;
 
@@ -317,10 +541,185 @@ mod tests {
         ) {
             let new_symbols = symbol_table.symbols_tail(starting_id);
             assert_eq!(3, new_symbols.len());
-            assert_eq!("foo", new_symbols[0].as_str());
-            assert_eq!("bar", new_symbols[1].as_str());
-            assert_eq!("baz", new_symbols[2].as_str());
+            assert_eq!(Some("foo"), new_symbols[0].as_deref());
+            assert_eq!(Some("bar"), new_symbols[1].as_deref());
+            assert_eq!(Some("baz"), new_symbols[2].as_deref());
+        }
+    }
+
+    fn catalog() -> MapCatalog {
+        let mut catalog = MapCatalog::new();
+        let symbols = |texts: &[&str]| -> Vec<Option<String>> {
+            texts.iter().map(|text| Some(text.to_string())).collect()
+        };
+        catalog.insert_table(SharedSymbolTable::new("shared", 1, symbols(&["a", "b"])));
+        catalog.insert_table(SharedSymbolTable::new(
+            "shared",
+            2,
+            symbols(&["a", "b", "c"]),
+        ));
+        catalog
+    }
+
+    // Reads every top-level value in the provided text Ion as a symbol using a Reader with the
+    // test catalog. Symbols whose text is unknown are returned as None.
+    fn read_symbols(text: &str) -> IonResult<Vec<Option<String>>> {
+        let mut reader = Reader::new(TextIonCursor::new(text.as_bytes()));
+        reader.set_catalog(catalog());
+        let mut symbols = vec![];
+        while let Some((IonType::Symbol, false)) = reader.next()? {
+            let sid = reader.read_symbol_id()?.unwrap();
+            symbols.push(
+                reader
+                    .symbol_table()
+                    .text_for(sid)
+                    .map(|text| text.to_string()),
+            );
         }
+        Ok(symbols)
+    }
+
+    #[rstest]
+    #[case::exact_match(
+        r#"$ion_symbol_table::{imports: [{name: "shared", version: 1}], symbols: ["d"]}
+           $10 $11 $12"#,
+        vec![Some("a"), Some("b"), Some("d")],
+    )]
+    #[case::default_version(
+        r#"$ion_symbol_table::{imports: [{name: "shared"}], symbols: ["d"]}
+           $10 $11 $12"#,
+        vec![Some("a"), Some("b"), Some("d")],
+    )]
+    #[case::smaller_max_id(
+        r#"$ion_symbol_table::{imports: [{name: "shared", version: 2, max_id: 1}], symbols: ["d"]}
+           $10 $11"#,
+        vec![Some("a"), Some("d")],
+    )]
+    #[case::larger_max_id(
+        r#"$ion_symbol_table::{imports: [{name: "shared", version: 1, max_id: 3}], symbols: ["d"]}
+           $10 $11 $12 $13"#,
+        vec![Some("a"), Some("b"), None, Some("d")],
+    )]
+    #[case::closest_version(
+        r#"$ion_symbol_table::{imports: [{name: "shared", version: 5, max_id: 3}]}
+           $10 $11 $12"#,
+        vec![Some("a"), Some("b"), Some("c")],
+    )]
+    #[case::not_in_catalog(
+        r#"$ion_symbol_table::{imports: [{name: "unknown", version: 1, max_id: 2}], symbols: ["d"]}
+           $10 $11 $12"#,
+        vec![None, None, Some("d")],
+    )]
+    #[case::multiple_imports(
+        r#"$ion_symbol_table::{
+               imports: [{name: "shared", version: 1}, {name: "unknown", max_id: 1}, {name: "shared", version: 2}]
+           }
+           $10 $11 $12 $13 $14 $15"#,
+        vec![Some("a"), Some("b"), None, Some("a"), Some("b"), Some("c")],
+    )]
+    #[case::ignored_imports(
+        r#"$ion_symbol_table::{symbols: ["d"]}
+           $ion_symbol_table::{imports: [{version: 1}, {name: "$ion"}, 5], symbols: ["e"]}
+           $10"#,
+        vec![Some("e")],
+    )]
+    #[case::non_append_symbol(
+        r#"$ion_symbol_table::{symbols: ["d"]}
+           $ion_symbol_table::{imports: foo, symbols: ["e"]}
+           $10"#,
+        vec![Some("e")],
+    )]
+    fn read_shared_imports(#[case] text: &str, #[case] expected: Vec<Option<&str>>) {
+        let expected: Vec<Option<String>> = expected
+            .into_iter()
+            .map(|text| text.map(|text| text.to_string()))
+            .collect();
+        assert_eq!(expected, read_symbols(text).unwrap());
+    }
+
+    #[rstest]
+    #[case::unknown_fields(
+        r#"$ion_symbol_table::{foo: 1, symbols: ["a"], $99: "b", name: "c"}
+           $10"#,
+        vec![Some("a")],
+    )]
+    #[case::non_string_symbols(
+        r#"$ion_symbol_table::{symbols: ["a", 1, null.string, b, "c"]}
+           $10 $11 $12 $13 $14"#,
+        vec![Some("a"), None, None, None, Some("c")],
+    )]
+    #[case::unexpected_field_types(
+        r#"$ion_symbol_table::{symbols: ["a"]}
+           $ion_symbol_table::{imports: "$ion_symbol_table", symbols: "b"}
+           $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]}
+           $10"#,
+        vec![Some("c")],
+    )]
+    fn read_malformed_symbol_tables(#[case] text: &str, #[case] expected: Vec<Option<&str>>) {
+        let expected: Vec<Option<String>> = expected
+            .into_iter()
+            .map(|text| text.map(|text| text.to_string()))
+            .collect();
+        assert_eq!(expected, read_symbols(text).unwrap());
+    }
+
+    #[rstest]
+    #[case::duplicate_symbols(r#"$ion_symbol_table::{symbols: ["a"], symbols: ["b"]}"#)]
+    #[case::duplicate_imports(
+        r#"$ion_symbol_table::{imports: $ion_symbol_table, imports: [{name: "foo", max_id: 1}]}"#
+    )]
+    fn read_invalid_symbol_tables(#[case] text: &str) {
+        match read_symbols(text) {
+            Err(IonError::DecodingError { .. }) => {}
+            other => panic!("Expected a decoding error, found {:?}", other),
+        }
+    }
+
+    #[rstest]
+    #[case::not_in_catalog(
+        r#"$ion_symbol_table::{imports: [{name: "unknown", max_id: 9223372036854775807}]} 1"#
+    )]
+    #[case::in_catalog(
+        r#"$ion_symbol_table::{imports: [{name: "shared", version: 1, max_id: 9223372036854775807}]} 1"#
+    )]
+    #[case::just_over_the_limit(
+        r#"$ion_symbol_table::{imports: [{name: "unknown", max_id: 1048577}]} 1"#
+    )]
+    fn read_import_with_huge_max_id(#[case] text: &str) {
+        match read_symbols(text) {
+            Err(IonError::DecodingError { .. }) => {}
+            other => panic!("Expected a decoding error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn import_not_in_catalog_without_max_id() {
+        let text = r#"$ion_symbol_table::{imports: [{name: "unknown", version: 1}]} 1"#;
+        assert!(read_symbols(text).is_err());
+    }
+
+    #[test]
+    fn read_symbol_with_unknown_text() -> IonResult<()> {
+        let text = r#"$ion_symbol_table::{imports: [{name: "unknown", max_id: 1}]} $10"#;
+        let mut reader = Reader::new(TextIonCursor::new(text.as_bytes()));
+        assert_eq!(Some((IonType::Symbol, false)), reader.next()?);
+        assert!(reader.read_symbol().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn read_annotations_with_unknown_text() -> IonResult<()> {
+        let text = r#"$ion_symbol_table::{imports: [{name: "unknown", max_id: 1}]} a::$10::$11::5"#;
+        let mut reader = Reader::new(TextIonCursor::new(text.as_bytes()));
+        assert_eq!(Some((IonType::Integer, false)), reader.next()?);
+        let annotations: Vec<IonResult<&str>> = reader.annotations().collect();
+        assert_eq!(3, annotations.len());
+        assert_eq!(&Ok("a"), &annotations[0]);
+        // $10 was imported from a table that is not in the catalog
+        assert!(annotations[1].is_err());
+        // $11 is not defined at all
+        assert!(annotations[2].is_err());
+        Ok(())
     }
 
     #[test]
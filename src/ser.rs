@@ -0,0 +1,721 @@
+//! Serializes Rust data structures as Ion using [serde](https://serde.rs).
+//!
+//! Values are mapped onto the Ion data model as follows:
+//! * Integers of any width become Ion integers; floats become Ion floats.
+//! * Strings and `char`s become Ion strings. Byte arrays (for example, those marked with
+//!   `#[serde(with = "serde_bytes")]`) become Ion blobs.
+//! * `None`, `()` and unit structs become `null`.
+//! * Sequences and tuples become Ion lists; maps and structs become Ion structs. Map keys must
+//!   be strings (or values that can be written as strings, like integers).
+//! * Unit enum variants become symbols. Other enum variants annotate their contents with the
+//!   variant name, so `Shape::Circle { radius: 2 }` becomes `Circle::{radius: 2}`. JSON cannot
+//!   represent annotations, so serializing a non-unit variant as JSON is an error.
+//! * [`Timestamp`] and [`Decimal`] are written as Ion timestamps and decimals.
+//!
+//! ```
+//! # use ion_rs::result::IonResult;
+//! # use ion_rs::value::writer::{Format, TextKind};
+//! # fn main() -> IonResult<()> {
+//! let bytes = ion_rs::to_vec(&vec![1, 2, 3], Format::Text(TextKind::Compact))?;
+//! assert_eq!(b"[1,2,3,]\n", bytes.as_slice());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io;
+
+use num_bigint::BigInt;
+use serde::ser::{self, Serialize};
+
+use crate::binary::binary_writer::BinaryWriter;
+use crate::cursor::RawSymbolToken;
+use crate::result::{illegal_operation, illegal_operation_raw, IonError, IonResult};
+use crate::text::writer::TextWriter;
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
+use crate::value::writer::{Format, TextKind};
+use crate::writer::IonWriter;
+use crate::IonType;
+
+// The Serialize implementations of Timestamp and Decimal wrap their Ion text in a newtype struct
+// with one of these names so that the Serializer can recognize them and write the native Ion type.
+// Other data formats see the Ion text as a string.
+pub(crate) const TIMESTAMP_NEWTYPE_NAME: &str = "$__ion_rs_timestamp__";
+pub(crate) const DECIMAL_NEWTYPE_NAME: &str = "$__ion_rs_decimal__";
+
+/// Serializes `value` as a single top-level Ion value in the specified format.
+pub fn to_vec<T>(value: &T, format: Format) -> IonResult<Vec<u8>>
+where
+    T: ?Sized + Serialize,
+{
+    let mut output = Vec::new();
+    to_writer(&mut output, value, format)?;
+    Ok(output)
+}
+
+/// Serializes `value` as a single top-level Ion value in the specified format, writing the
+/// encoded bytes to `output`.
+pub fn to_writer<W, T>(output: W, value: &T, format: Format) -> IonResult<()>
+where
+    W: io::Write,
+    T: ?Sized + Serialize,
+{
+    match format {
+        Format::Text(TextKind::Compact) => {
+            serialize_with(Serializer::new(TextWriter::new(output)), value)
+        }
+        Format::Text(TextKind::Pretty) => {
+            serialize_with(Serializer::new(TextWriter::pretty(output)), value)
+        }
+        Format::Binary => serialize_with(Serializer::new(BinaryWriter::new(output)), value),
+        Format::Json(TextKind::Compact) => {
+            serialize_with(Serializer::json(TextWriter::json(output)), value)
+        }
+        Format::Json(TextKind::Pretty) => {
+            serialize_with(Serializer::json(TextWriter::pretty_json(output)), value)
+        }
+    }
+}
+
+fn serialize_with<I, T>(mut serializer: Serializer<I>, value: &T) -> IonResult<()>
+where
+    I: IonWriter,
+    T: ?Sized + Serialize,
+{
+    value.serialize(&mut serializer)?;
+    serializer.into_inner().flush()
+}
+
+/// Formats a single scalar as Ion text, without the newline that follows top-level values.
+pub(crate) fn to_ion_text<F>(write: F) -> IonResult<String>
+where
+    F: FnOnce(&mut TextWriter<Vec<u8>>) -> IonResult<()>,
+{
+    let mut writer = TextWriter::new(Vec::new());
+    write(&mut writer)?;
+    let output = writer.into_output()?;
+    match String::from_utf8(output) {
+        Ok(text) => Ok(text.trim_end().to_string()),
+        Err(e) => illegal_operation(format!("The text writer wrote invalid UTF-8: {}", e)),
+    }
+}
+
+/// A serde [`Serializer`](ser::Serializer) that writes each serialized value to an
+/// [`IonWriter`]. Any number of values can be serialized in sequence; each becomes a top-level
+/// value in the writer's output. Call [`IonWriter::flush`] on the writer once serialization is
+/// complete.
+pub struct Serializer<W: IonWriter> {
+    writer: W,
+    // Enum variant names that will annotate the next value.
+    annotations: Vec<&'static str>,
+    // Whether the writer produces JSON, which drops annotations.
+    json: bool,
+}
+
+impl<W: IonWriter> Serializer<W> {
+    /// Creates a serializer for a writer that produces Ion text or binary. Enum variant names are
+    /// written as annotations on the variant's value.
+    pub fn new(writer: W) -> Self {
+        Serializer {
+            writer,
+            annotations: Vec::new(),
+            json: false,
+        }
+    }
+
+    /// Creates a serializer for a writer that produces JSON, such as [`TextWriter::json`]. JSON
+    /// has no annotations to hold enum variant names, so non-unit variants result in an Err
+    /// rather than being written without their names.
+    pub fn json(writer: W) -> Self {
+        Serializer {
+            writer,
+            annotations: Vec::new(),
+            json: true,
+        }
+    }
+
+    /// Returns a mutable reference to the wrapped [`IonWriter`].
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consumes the serializer and returns the wrapped [`IonWriter`].
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    // Applies any pending annotations to the next value. Nested enums (for example, a newtype
+    // variant containing another enum) accumulate annotations before a value is written.
+    fn annotate(&mut self) -> IonResult<&mut W> {
+        if self.json && !self.annotations.is_empty() {
+            return illegal_operation(format!(
+                "Cannot serialize the enum variant '{}' as JSON, which has no annotations.",
+                self.annotations.join("::")
+            ));
+        }
+        if !self.annotations.is_empty() {
+            let annotations: Vec<RawSymbolToken> = self
+                .annotations
+                .drain(..)
+                .map(RawSymbolToken::Text)
+                .collect();
+            self.writer.set_annotations(&annotations)?;
+        }
+        Ok(&mut self.writer)
+    }
+
+    fn step_in(&mut self, ion_type: IonType) -> IonResult<Compound<W>> {
+        self.annotate()?.step_in(ion_type)?;
+        Ok(Compound {
+            serializer: self,
+            key: None,
+        })
+    }
+}
+
+impl<'a, W: IonWriter> ser::Serializer for &'a mut Serializer<W> {
+    type Ok = ();
+    type Error = IonError;
+    type SerializeSeq = Compound<'a, W>;
+    type SerializeTuple = Compound<'a, W>;
+    type SerializeTupleStruct = Compound<'a, W>;
+    type SerializeTupleVariant = Compound<'a, W>;
+    type SerializeMap = Compound<'a, W>;
+    type SerializeStruct = Compound<'a, W>;
+    type SerializeStructVariant = Compound<'a, W>;
+
+    fn serialize_bool(self, v: bool) -> IonResult<()> {
+        self.annotate()?.write_bool(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> IonResult<()> {
+        self.annotate()?.write_i64(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> IonResult<()> {
+        self.annotate()?.write_big_int(&BigInt::from(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> IonResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u64(self, v: u64) -> IonResult<()> {
+        if v <= i64::MAX as u64 {
+            return self.serialize_i64(v as i64);
+        }
+        self.annotate()?.write_big_int(&BigInt::from(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> IonResult<()> {
+        self.annotate()?.write_big_int(&BigInt::from(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> IonResult<()> {
+        self.annotate()?.write_f32(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> IonResult<()> {
+        self.annotate()?.write_f64(v)
+    }
+
+    fn serialize_char(self, v: char) -> IonResult<()> {
+        self.serialize_str(v.encode_utf8(&mut [0u8; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> IonResult<()> {
+        self.annotate()?.write_string(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> IonResult<()> {
+        self.annotate()?.write_blob(v)
+    }
+
+    fn serialize_none(self) -> IonResult<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> IonResult<()> {
+        self.annotate()?.write_null(IonType::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> IonResult<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> IonResult<()> {
+        self.annotate()?.write_symbol(RawSymbolToken::Text(variant))
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        match name {
+            TIMESTAMP_NEWTYPE_NAME => {
+                let text = value.serialize(TextSerializer)?;
+                let timestamp = crate::de::parse_ion_text(&text, crate::Reader::read_timestamp)?;
+                self.annotate()?.write_timestamp(&timestamp)
+            }
+            DECIMAL_NEWTYPE_NAME => {
+                let text = value.serialize(TextSerializer)?;
+                let decimal = crate::de::parse_ion_text(&text, crate::Reader::read_decimal)?;
+                self.annotate()?.write_decimal(&decimal)
+            }
+            _ => value.serialize(self),
+        }
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.annotations.push(variant);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> IonResult<Self::SerializeSeq> {
+        self.step_in(IonType::List)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> IonResult<Self::SerializeTuple> {
+        self.step_in(IonType::List)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> IonResult<Self::SerializeTupleStruct> {
+        self.step_in(IonType::List)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> IonResult<Self::SerializeTupleVariant> {
+        self.annotations.push(variant);
+        self.step_in(IonType::List)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> IonResult<Self::SerializeMap> {
+        self.step_in(IonType::Struct)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> IonResult<Self::SerializeStruct> {
+        self.step_in(IonType::Struct)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> IonResult<Self::SerializeStructVariant> {
+        self.annotations.push(variant);
+        self.step_in(IonType::Struct)
+    }
+}
+
+/// Serializes the elements of an Ion list or the fields of an Ion struct.
+pub struct Compound<'a, W: IonWriter> {
+    serializer: &'a mut Serializer<W>,
+    // The name of the map entry whose value will be serialized next.
+    key: Option<String>,
+}
+
+impl<'a, W: IonWriter> Compound<'a, W> {
+    fn serialize_element<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn serialize_field<T>(&mut self, name: &str, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.serializer
+            .writer
+            .set_field_name(RawSymbolToken::Text(name))?;
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn end(self) -> IonResult<()> {
+        self.serializer.writer.step_out()
+    }
+}
+
+impl<'a, W: IonWriter> ser::SerializeSeq for Compound<'a, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Compound::serialize_element(self, value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        Compound::end(self)
+    }
+}
+
+impl<'a, W: IonWriter> ser::SerializeTuple for Compound<'a, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Compound::serialize_element(self, value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        Compound::end(self)
+    }
+}
+
+impl<'a, W: IonWriter> ser::SerializeTupleStruct for Compound<'a, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Compound::serialize_element(self, value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        Compound::end(self)
+    }
+}
+
+impl<'a, W: IonWriter> ser::SerializeTupleVariant for Compound<'a, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Compound::serialize_element(self, value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        Compound::end(self)
+    }
+}
+
+impl<'a, W: IonWriter> ser::SerializeMap for Compound<'a, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.key = Some(key.serialize(TextSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = match self.key.take() {
+            Some(key) => key,
+            None => return illegal_operation("serialize_value was called before serialize_key"),
+        };
+        Compound::serialize_field(self, &key, value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        Compound::end(self)
+    }
+}
+
+impl<'a, W: IonWriter> ser::SerializeStruct for Compound<'a, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Compound::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        Compound::end(self)
+    }
+}
+
+impl<'a, W: IonWriter> ser::SerializeStructVariant for Compound<'a, W> {
+    type Ok = ();
+    type Error = IonError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Compound::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> IonResult<()> {
+        Compound::end(self)
+    }
+}
+
+/// Serializes values that can be represented as text, such as map keys, to a String. Values that
+/// have no text representation (like containers) result in an Err.
+pub(crate) struct TextSerializer;
+
+impl TextSerializer {
+    fn unsupported<T>(kind: &str) -> IonResult<T> {
+        illegal_operation(format!("Cannot use a {} as a struct field name", kind))
+    }
+}
+
+impl ser::Serializer for TextSerializer {
+    type Ok = String;
+    type Error = IonError;
+    type SerializeSeq = ser::Impossible<String, IonError>;
+    type SerializeTuple = ser::Impossible<String, IonError>;
+    type SerializeTupleStruct = ser::Impossible<String, IonError>;
+    type SerializeTupleVariant = ser::Impossible<String, IonError>;
+    type SerializeMap = ser::Impossible<String, IonError>;
+    type SerializeStruct = ser::Impossible<String, IonError>;
+    type SerializeStructVariant = ser::Impossible<String, IonError>;
+
+    fn serialize_bool(self, v: bool) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i128(self, v: i128) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u128(self, v: u128) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_f32(self, _v: f32) -> IonResult<String> {
+        Self::unsupported("float")
+    }
+
+    fn serialize_f64(self, _v: f64) -> IonResult<String> {
+        Self::unsupported("float")
+    }
+
+    fn serialize_char(self, v: char) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> IonResult<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> IonResult<String> {
+        Self::unsupported("byte array")
+    }
+
+    fn serialize_none(self) -> IonResult<String> {
+        Self::unsupported("null")
+    }
+
+    fn serialize_some<T>(self, value: &T) -> IonResult<String>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> IonResult<String> {
+        Self::unsupported("null")
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> IonResult<String> {
+        Self::unsupported("null")
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> IonResult<String> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> IonResult<String>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> IonResult<String>
+    where
+        T: ?Sized + Serialize,
+    {
+        Self::unsupported("enum variant with data")
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> IonResult<Self::SerializeSeq> {
+        Self::unsupported("sequence")
+    }
+
+    fn serialize_tuple(self, _len: usize) -> IonResult<Self::SerializeTuple> {
+        Self::unsupported("tuple")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> IonResult<Self::SerializeTupleStruct> {
+        Self::unsupported("tuple struct")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> IonResult<Self::SerializeTupleVariant> {
+        Self::unsupported("enum variant with data")
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> IonResult<Self::SerializeMap> {
+        Self::unsupported("map")
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> IonResult<Self::SerializeStruct> {
+        Self::unsupported("struct")
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> IonResult<Self::SerializeStructVariant> {
+        Self::unsupported("enum variant with data")
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let text =
+            to_ion_text(|writer| writer.write_timestamp(self)).map_err(ser::Error::custom)?;
+        serializer.serialize_newtype_struct(TIMESTAMP_NEWTYPE_NAME, &text)
+    }
+}
+
+impl Serialize for Decimal {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let text = to_ion_text(|writer| writer.write_decimal(self)).map_err(ser::Error::custom)?;
+        serializer.serialize_newtype_struct(DECIMAL_NEWTYPE_NAME, &text)
+    }
+}
+
+impl ser::Error for IonError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        illegal_operation_raw(msg.to_string())
+    }
+}
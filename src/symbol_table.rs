@@ -5,7 +5,7 @@ use crate::types::SymbolId;
 
 /// Stores mappings from Symbol IDs to text and vice-versa.
 pub struct SymbolTable {
-    symbols_by_id: Vec<String>,
+    symbols_by_id: Vec<Option<String>>,
     ids_by_text: HashMap<String, SymbolId>,
 }
 
@@ -23,7 +23,7 @@ impl SymbolTable {
     // Interns the v1.0 system symbols
     fn initialize(&mut self) {
         for (id, text) in v1_0::SYSTEM_SYMBOLS.iter().enumerate() {
-            self.symbols_by_id.push(text.to_string());
+            self.symbols_by_id.push(Some(text.to_string()));
             self.ids_by_text.insert(text.to_string(), id);
         }
     }
@@ -42,30 +42,53 @@ impl SymbolTable {
 
         // Otherwise, intern it and return the new ID.
         let id = self.symbols_by_id.len();
-        self.symbols_by_id.push(text.to_string());
+        self.symbols_by_id.push(Some(text.to_string()));
         self.ids_by_text.insert(text, id);
         id
     }
 
+    /// Assigns the next symbol ID to the provided text, even if the text is already defined.
+    /// Lookups by text will continue to return the lowest symbol ID with that text.
+    pub fn add_symbol(&mut self, text: String) -> SymbolId {
+        let id = self.symbols_by_id.len();
+        self.symbols_by_id.push(Some(text.clone()));
+        self.ids_by_text.entry(text).or_insert(id);
+        id
+    }
+
+    /// Assigns the next symbol ID to a symbol whose text is unknown.
+    pub fn add_placeholder(&mut self) -> SymbolId {
+        let id = self.symbols_by_id.len();
+        self.symbols_by_id.push(None);
+        id
+    }
+
     /// If defined, returns the Symbol ID associated with the provided text.
     pub fn sid_for<A: AsRef<str>>(&self, text: &A) -> Option<SymbolId> {
         self.ids_by_text.get(text.as_ref()).copied()
     }
 
-    /// If defined, returns the text associated with the provided Symbol ID.
+    /// If defined, returns the text associated with the provided Symbol ID. Returns None if the
+    /// Symbol ID is not defined or if its text is unknown.
     pub fn text_for(&self, sid: usize) -> Option<&str> {
-        self.symbols_by_id.get(sid).map(|text| text.as_str())
+        self.symbols_by_id.get(sid)?.as_deref()
+    }
+
+    /// Returns true if the provided Symbol ID is defined, even if its text is unknown.
+    pub fn sid_is_valid(&self, sid: usize) -> bool {
+        sid < self.symbols_by_id.len()
     }
 
-    // Returns a slice of references to the symbol text stored in the table.
-    pub fn symbols(&self) -> &[String] {
+    // Returns a slice of references to the symbol text stored in the table. Symbols whose text
+    // is unknown are None.
+    pub fn symbols(&self) -> &[Option<String>] {
         &self.symbols_by_id
     }
 
     // Returns a slice of references to the symbol text stored in the table starting at the given
     // symbol ID. If a symbol table append occurs during reading, this function can be used to
     // easily view the new symbols that has been added to the table.
-    pub fn symbols_tail(&self, start: usize) -> &[String] {
+    pub fn symbols_tail(&self, start: usize) -> &[Option<String>] {
         &self.symbols_by_id[start..]
     }
 
@@ -0,0 +1,1073 @@
+use std::convert::TryFrom;
+use std::io::BufRead;
+use std::str;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset, TimeZone};
+use nom::IResult;
+use num_bigint::BigInt;
+
+use crate::cursor::{Cursor, IntegerSize, RawSymbolToken, StreamItem};
+use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::text::parsers::containers::{
+    list_delimiter, list_value, sexp_value, struct_delimiter, struct_field, top_level_value,
+};
+use crate::text::parsers::whitespace;
+use crate::text::{AnnotatedTextStreamItem, TextStreamItem, TextSymbolToken};
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
+use crate::types::{IonType, SymbolId};
+
+// The maximum number of characters of unparsed input to include in an error message.
+const ERROR_CONTEXT_LENGTH: usize = 32;
+
+// Appended to the buffer once the data source has been exhausted. The newline acts as a stop
+// character for any value at the end of the stream. The NUL cannot begin any Ion value, which
+// causes parsers that look ahead for more input (e.g. long strings) to stop.
+const END_OF_INPUT: &str = "\n\u{0}";
+
+/// The value on which the cursor is currently positioned along with its field name and
+/// annotations, if any.
+#[derive(Debug, Clone, PartialEq)]
+struct TextValue {
+    field_name: Option<TextSymbolToken>,
+    annotations: Vec<TextSymbolToken>,
+    item: TextStreamItem,
+}
+
+impl TextValue {
+    fn new(field_name: Option<TextSymbolToken>, value: AnnotatedTextStreamItem) -> TextValue {
+        TextValue {
+            field_name,
+            annotations: value.annotations,
+            item: value.item,
+        }
+    }
+}
+
+impl From<AnnotatedTextStreamItem> for TextValue {
+    fn from(value: AnnotatedTextStreamItem) -> Self {
+        TextValue::new(None, value)
+    }
+}
+
+/// A streaming cursor over text Ion. Text is pulled from the provided [BufRead] one chunk at a
+/// time; whenever the parser reaches the end of the text that has been read so far without
+/// being able to make a decision, the next chunk is appended to the working buffer and parsing
+/// is attempted again.
+pub struct TextIonCursor<R: BufRead> {
+    // The file, socket, array, or other data source containing text Ion
+    data_source: R,
+    // Text that has been read from the data source but not yet discarded
+    buffer: String,
+    // The number of bytes at the beginning of `buffer` that have already been parsed
+    buffer_offset: usize,
+    // Bytes at the end of the last chunk that did not yet form a complete UTF-8 character
+    utf8_remainder: Vec<u8>,
+    // Whether the data source has been exhausted
+    is_exhausted: bool,
+    // The (major, minor) version pair of the stream being read. Defaults to (1, 0)
+    ion_version: (u8, u8),
+    // The value on which the cursor is currently sitting, if any
+    value: Option<TextValue>,
+    // The symbol IDs of the current value's annotations that were written as `$n`
+    annotation_ids: Vec<SymbolId>,
+    // The types of the containers into which the cursor has stepped. Empty at the top level.
+    parents: Vec<IonType>,
+    // Whether the cursor has encountered the end of the container it is traversing
+    is_at_container_end: bool,
+}
+
+impl<R: BufRead> TextIonCursor<R> {
+    pub fn new(data_source: R) -> Self {
+        TextIonCursor {
+            data_source,
+            buffer: String::new(),
+            buffer_offset: 0,
+            utf8_remainder: Vec::new(),
+            is_exhausted: false,
+            ion_version: (1, 0),
+            value: None,
+            annotation_ids: Vec::new(),
+            parents: Vec::new(),
+            is_at_container_end: false,
+        }
+    }
+
+    fn current_item(&self) -> Option<&TextStreamItem> {
+        self.value.as_ref().map(|value| &value.item)
+    }
+
+    fn set_current_value(&mut self, value: TextValue) {
+        self.annotation_ids.clear();
+        for annotation in &value.annotations {
+            if let TextSymbolToken::SymbolId(sid) = annotation {
+                self.annotation_ids.push(*sid);
+            }
+        }
+        self.value = Some(value);
+    }
+
+    fn clear_current_value(&mut self) {
+        self.annotation_ids.clear();
+        self.value = None;
+    }
+
+    /// Reads the next item at the current depth from the input, returning None if the end of the
+    /// stream was reached.
+    fn read_next_value(&mut self) -> IonResult<Option<TextValue>> {
+        let value = match self.parents.last() {
+            None => self.parse_next(top_level_value)?.map(TextValue::from),
+            Some(IonType::List) => self.parse_next(list_value)?.map(TextValue::from),
+            Some(IonType::SExpression) => self.parse_next(sexp_value)?.map(TextValue::from),
+            Some(IonType::Struct) => self
+                .parse_next(struct_field)?
+                .map(|(field_name, value)| TextValue::new(field_name, value)),
+            Some(ion_type) => unreachable!("{} is not a container type", ion_type),
+        };
+        Ok(value)
+    }
+
+    /// After stepping out of a container, matches the delimiter that the parent container
+    /// requires after each of its values.
+    fn read_container_delimiter(&mut self) -> IonResult<()> {
+        let delimiter = match self.parents.last() {
+            Some(IonType::List) => self.parse_next(list_delimiter)?,
+            Some(IonType::Struct) => self.parse_next(struct_delimiter)?,
+            _ => return Ok(()),
+        };
+        if delimiter.is_none() {
+            return decoding_error(format!(
+                "The input ended inside of a {}.",
+                self.parents.last().unwrap()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Applies the provided parser to the unconsumed text in the buffer. If the parser needs more
+    /// text to make a decision, the next chunk of input is read from the data source and the
+    /// parser is tried again. Returns None if the data source was exhausted and only whitespace
+    /// remains.
+    fn parse_next<T, P>(&mut self, parser: P) -> IonResult<Option<T>>
+    where
+        P: Fn(&str) -> IResult<&str, T>,
+    {
+        use nom::Err::{Error, Failure, Incomplete};
+        loop {
+            let input = &self.buffer[self.buffer_offset..];
+            let parse_error = match parser(input) {
+                Ok((remaining_text, item)) => {
+                    self.buffer_offset += input.len() - remaining_text.len();
+                    return Ok(Some(item));
+                }
+                Err(Incomplete(_)) => None,
+                Err(Error(e)) | Err(Failure(e)) => Some(format!(
+                    "Could not parse text Ion at '{}'",
+                    error_context(e.input)
+                )),
+            };
+
+            if parse_error.is_none() && !self.is_exhausted {
+                if self.load_next_chunk()? == 0 {
+                    // Many encodings (integers, symbols, timestamps, etc.) are only complete once
+                    // they are followed by a stop character, and others look ahead to see whether
+                    // more of the same value follows. Once the data source has been exhausted, we
+                    // append a terminator so that a value at the end of the stream can be
+                    // recognized.
+                    self.is_exhausted = true;
+                    self.buffer.push_str(END_OF_INPUT);
+                }
+                continue;
+            }
+
+            // If only whitespace remains in an exhausted stream, we've reached the end.
+            if self.is_exhausted && self.remaining_text_is_empty() {
+                self.buffer_offset = self.buffer.len();
+                return Ok(None);
+            }
+
+            return decoding_error(parse_error.unwrap_or_else(|| {
+                format!(
+                    "Unexpected end of input while parsing '{}'",
+                    error_context(&self.buffer[self.buffer_offset..])
+                )
+            }));
+        }
+    }
+
+    /// Returns true if no text other than whitespace, comments, and the [END_OF_INPUT] terminator
+    /// remains in the buffer.
+    fn remaining_text_is_empty(&self) -> bool {
+        let text = &self.buffer[self.buffer_offset..];
+        let remaining_text = match whitespace(text) {
+            Ok((remaining_text, _whitespace)) => remaining_text,
+            Err(_) => text,
+        };
+        // The whitespace parser may or may not have consumed the terminator's newline.
+        END_OF_INPUT.ends_with(remaining_text)
+    }
+
+    /// Appends the next chunk of text from the data source to the buffer, returning the number of
+    /// bytes that were read. Text that has already been parsed is discarded first.
+    fn load_next_chunk(&mut self) -> IonResult<usize> {
+        if self.buffer_offset > 0 {
+            self.buffer.drain(..self.buffer_offset);
+            self.buffer_offset = 0;
+        }
+
+        let bytes = self.data_source.fill_buf()?;
+        let bytes_read = bytes.len();
+        if bytes_read == 0 {
+            if !self.utf8_remainder.is_empty() {
+                return decoding_error("The input ended with an incomplete UTF-8 character.");
+            }
+            return Ok(0);
+        }
+        self.utf8_remainder.extend_from_slice(bytes);
+        self.data_source.consume(bytes_read);
+
+        // A chunk boundary can fall in the middle of a multi-byte character. If that happens,
+        // the trailing bytes are held back until the next chunk completes the character.
+        let valid_length = match str::from_utf8(&self.utf8_remainder) {
+            Ok(text) => text.len(),
+            Err(error) if error.error_len().is_none() => error.valid_up_to(),
+            Err(error) => {
+                return decoding_error(format!("The input was not valid UTF-8: {:?}", error));
+            }
+        };
+        if let Ok(text) = str::from_utf8(&self.utf8_remainder[..valid_length]) {
+            self.buffer.push_str(text);
+        }
+        self.utf8_remainder.drain(..valid_length);
+        Ok(bytes_read)
+    }
+}
+
+/// Returns a prefix of the provided text that can be used to describe the location of a parsing
+/// failure.
+fn error_context(text: &str) -> String {
+    text.chars().take(ERROR_CONTEXT_LENGTH).collect()
+}
+
+impl<R: BufRead> Cursor for TextIonCursor<R> {
+    type DataSource = R;
+
+    fn ion_version(&self) -> (u8, u8) {
+        self.ion_version
+    }
+
+    fn next(&mut self) -> IonResult<Option<StreamItem>> {
+        if self.is_at_container_end {
+            return Ok(None);
+        }
+
+        // If the cursor is sitting on a container that the user did not step into, skip over the
+        // container's contents.
+        if let Some(true) = self.current_item().map(|item| item.is_container_start()) {
+            self.step_in()?;
+            self.step_out()?;
+        }
+        self.clear_current_value();
+
+        let value = match self.read_next_value()? {
+            Some(value) => value,
+            None if self.parents.is_empty() => return Ok(None),
+            None => {
+                return decoding_error(format!(
+                    "The input ended inside of a {}.",
+                    self.parents.last().unwrap()
+                ));
+            }
+        };
+
+        if let TextStreamItem::VersionMarker(major, minor) = value.item {
+            if (major, minor) != (1, 0) {
+                return decoding_error(format!(
+                    "Ion version {}.{} is not supported.",
+                    major, minor
+                ));
+            }
+            self.ion_version = (major, minor);
+            return Ok(Some(StreamItem::VersionMarker(major, minor)));
+        }
+
+        let ion_type = match value.item.ion_type() {
+            Some(ion_type) => ion_type,
+            None => {
+                // The item is the end of the container that the cursor is traversing.
+                self.is_at_container_end = true;
+                return Ok(None);
+            }
+        };
+        let is_null = value.item.is_null();
+        self.set_current_value(value);
+        Ok(Some(StreamItem::Value(ion_type, is_null)))
+    }
+
+    fn ion_type(&self) -> Option<IonType> {
+        self.current_item().and_then(|item| item.ion_type())
+    }
+
+    fn is_null(&self) -> bool {
+        self.current_item()
+            .map(|item| item.is_null())
+            .unwrap_or(false)
+    }
+
+    /// Text Ion annotations are usually written out inline and therefore have no symbol ID. Only
+    /// annotations written as `$n` are included in the returned slice; use
+    /// [raw_annotations](Cursor::raw_annotations) to see all of them.
+    fn annotation_ids(&self) -> &[SymbolId] {
+        &self.annotation_ids
+    }
+
+    /// Text Ion field names are usually written out inline and therefore have no symbol ID. This
+    /// method only returns a value if the field name was written as `$n`; use
+    /// [raw_field_name](Cursor::raw_field_name) to see any field name.
+    fn field_id(&self) -> Option<SymbolId> {
+        match self.value.as_ref()?.field_name.as_ref()? {
+            TextSymbolToken::SymbolId(sid) => Some(*sid),
+            TextSymbolToken::Text(_) => None,
+        }
+    }
+
+    fn raw_annotations<'a>(&'a self) -> Box<dyn Iterator<Item = RawSymbolToken<'a>> + 'a> {
+        match self.value.as_ref() {
+            Some(value) => Box::new(
+                value
+                    .annotations
+                    .iter()
+                    .map(|annotation| annotation.as_raw_symbol_token()),
+            ),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
+    fn raw_field_name(&self) -> Option<RawSymbolToken> {
+        self.value
+            .as_ref()?
+            .field_name
+            .as_ref()
+            .map(|field_name| field_name.as_raw_symbol_token())
+    }
+
+    fn read_null(&mut self) -> IonResult<Option<IonType>> {
+        match self.current_item() {
+            Some(TextStreamItem::Null(ion_type)) => Ok(Some(*ion_type)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_bool(&mut self) -> IonResult<Option<bool>> {
+        match self.current_item() {
+            Some(TextStreamItem::Boolean(value)) => Ok(Some(*value)),
+            _ => Ok(None),
+        }
+    }
+
+    fn integer_size(&self) -> Option<IntegerSize> {
+        match self.current_item() {
+            Some(TextStreamItem::Integer(_)) => Some(IntegerSize::I64),
+            Some(TextStreamItem::BigInteger(_)) => Some(IntegerSize::BigInt),
+            _ => None,
+        }
+    }
+
+    fn read_i64(&mut self) -> IonResult<Option<i64>> {
+        match self.current_item() {
+            Some(TextStreamItem::Integer(value)) => Ok(Some(*value)),
+            Some(TextStreamItem::BigInteger(value)) => decoding_error(format!(
+                "Found the integer {}, which is too large to fit in an i64.",
+                value
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_big_int(&mut self) -> IonResult<Option<BigInt>> {
+        match self.current_item() {
+            Some(TextStreamItem::Integer(value)) => Ok(Some(BigInt::from(*value))),
+            Some(TextStreamItem::BigInteger(value)) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_f32(&mut self) -> IonResult<Option<f32>> {
+        // Lossy if the value requires 64 bits
+        Ok(self.read_f64()?.map(|value| value as f32))
+    }
+
+    fn read_f64(&mut self) -> IonResult<Option<f64>> {
+        match self.current_item() {
+            Some(TextStreamItem::Float(value)) => Ok(Some(*value)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>> {
+        match self.current_item() {
+            Some(TextStreamItem::Decimal(value)) => Ok(Some(BigDecimal::try_from(value.clone())?)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Option<Decimal>> {
+        match self.current_item() {
+            Some(TextStreamItem::Decimal(value)) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_string(&mut self) -> IonResult<Option<String>> {
+        self.string_ref_map(|s: &str| s.into())
+    }
+
+    fn string_ref_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
+    where
+        F: FnOnce(&str) -> T,
+    {
+        match self.current_item() {
+            Some(TextStreamItem::String(text)) => Ok(Some(f(text.as_str()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn string_bytes_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
+    where
+        F: FnOnce(&[u8]) -> T,
+    {
+        self.string_ref_map(|s: &str| f(s.as_bytes()))
+    }
+
+    /// Text Ion symbols are usually written out inline and therefore have no symbol ID. If the
+    /// current value is such a symbol, this method returns an Err; use
+    /// [read_raw_symbol](Cursor::read_raw_symbol) instead. Symbols written as `$n` have the
+    /// symbol ID `n`.
+    fn read_symbol_id(&mut self) -> IonResult<Option<SymbolId>> {
+        match self.current_item() {
+            Some(TextStreamItem::Symbol(TextSymbolToken::SymbolId(sid))) => Ok(Some(*sid)),
+            Some(TextStreamItem::Symbol(TextSymbolToken::Text(text))) => {
+                illegal_operation(format!(
+                    "The symbol '{}' was written as text and has no symbol ID.",
+                    text
+                ))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn read_raw_symbol(&mut self) -> IonResult<Option<RawSymbolToken>> {
+        match self.current_item() {
+            Some(TextStreamItem::Symbol(token)) => Ok(Some(token.as_raw_symbol_token())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
+        self.blob_ref_map(|b| b.into())
+    }
+
+    fn blob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        match self.current_item() {
+            Some(TextStreamItem::Blob(bytes)) => Ok(Some(f(bytes.as_slice()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
+        self.clob_ref_map(|c| c.into())
+    }
+
+    fn clob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        match self.current_item() {
+            Some(TextStreamItem::Clob(bytes)) => Ok(Some(f(bytes.as_slice()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>> {
+        match self.current_item() {
+            Some(TextStreamItem::Timestamp(timestamp)) => {
+                // Timestamps store their fields in UTC. An unknown offset is treated as UTC.
+                let offset = timestamp.offset.unwrap_or_else(|| FixedOffset::east(0));
+                Ok(Some(offset.from_utc_datetime(&timestamp.date_time)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>> {
+        match self.current_item() {
+            Some(TextStreamItem::Timestamp(timestamp)) => Ok(Some(timestamp.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        let ion_type = match self.current_item() {
+            Some(TextStreamItem::ListStart) => IonType::List,
+            Some(TextStreamItem::SExpressionStart) => IonType::SExpression,
+            Some(TextStreamItem::StructStart) => IonType::Struct,
+            _ => {
+                return illegal_operation(format!(
+                    "You cannot step into a(n) {:?}",
+                    self.ion_type()
+                ));
+            }
+        };
+        self.parents.push(ion_type);
+        self.clear_current_value();
+        Ok(())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        if self.parents.is_empty() {
+            return illegal_operation("You cannot step out of the root level.");
+        }
+        // Skip any values remaining in the container.
+        while self.next()?.is_some() {}
+        self.parents.pop();
+        self.is_at_container_end = false;
+        self.clear_current_value();
+        self.read_container_delimiter()
+    }
+
+    fn depth(&self) -> usize {
+        self.parents.len()
+    }
+}
+
+#[cfg(test)]
+mod cursor_tests {
+    use std::io::BufReader;
+
+    use crate::cursor::{Cursor, IntegerSize, RawSymbolToken, StreamItem::*};
+    use crate::result::{IonError, IonResult};
+    use crate::text::cursor::TextIonCursor;
+    use crate::text::parsers::containers::{stream_item, top_level_value};
+    use crate::text::parsers::unit_test_support::parse_unwrap;
+    use crate::text::{TextStreamItem, TextSymbolToken};
+    use crate::types::decimal::Decimal;
+    use crate::types::timestamp::Timestamp;
+    use crate::value::AnyInt;
+    use crate::{IonType, Reader};
+    use num_bigint::BigInt;
+
+    fn text_cursor(text: &str) -> TextIonCursor<&[u8]> {
+        TextIonCursor::new(text.as_bytes())
+    }
+
+    fn top_level_value_test(ion_text: &str, expected: TextStreamItem) {
+        let (_remaining, value) = top_level_value(ion_text).unwrap();
+        assert_eq!(value.item, expected);
+    }
+
+    #[test]
+    fn test_read_single_top_level_values() -> IonResult<()> {
+        let tlv = top_level_value_test;
+        tlv(" null ", TextStreamItem::Null(IonType::Null));
+        tlv(" null.string ", TextStreamItem::Null(IonType::String));
+        tlv(" true ", TextStreamItem::Boolean(true));
+        tlv(" false ", TextStreamItem::Boolean(false));
+        tlv(" 738 ", TextStreamItem::Integer(738));
+        tlv(" 2.5e0 ", TextStreamItem::Float(2.5));
+        tlv(" 2.5 ", TextStreamItem::Decimal(Decimal::new(25, -1)));
+        tlv(
+            " 2007-07-12T ",
+            TextStreamItem::Timestamp(Timestamp::with_ymd(2007, 7, 12).build()?),
+        );
+        tlv(
+            " foo ",
+            TextStreamItem::Symbol(TextSymbolToken::Text("foo".to_owned())),
+        );
+        tlv(
+            " $10 ",
+            TextStreamItem::Symbol(TextSymbolToken::SymbolId(10)),
+        );
+        tlv(" \"hi!\" ", TextStreamItem::String("hi!".to_owned()));
+        tlv(
+            " {{ZW5jb2RlZA==}} ",
+            TextStreamItem::Blob(Vec::from("encoded".as_bytes())),
+        );
+        tlv(
+            " {{\"hello\"}} ",
+            TextStreamItem::Clob(Vec::from("hello".as_bytes())),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_stream_item_types() {
+        let expect_type = |text: &str, expected_ion_type: IonType| {
+            let value = parse_unwrap(stream_item, text);
+            assert_eq!(expected_ion_type, value.ion_type().unwrap());
+        };
+
+        expect_type("null ", IonType::Null);
+        expect_type("null.timestamp ", IonType::Timestamp);
+        expect_type("null.list ", IonType::List);
+        expect_type("true ", IonType::Boolean);
+        expect_type("false ", IonType::Boolean);
+        expect_type("5 ", IonType::Integer);
+        expect_type("-5 ", IonType::Integer);
+        expect_type("5.0 ", IonType::Decimal);
+        expect_type("-5.0 ", IonType::Decimal);
+        expect_type("5.0d0 ", IonType::Decimal);
+        expect_type("-5.0d0 ", IonType::Decimal);
+        expect_type("5.0e0 ", IonType::Float);
+        expect_type("-5.0e1_024 ", IonType::Float);
+        expect_type("\"foo\"", IonType::String);
+        expect_type("'''foo''' 1", IonType::String);
+        expect_type("foo ", IonType::Symbol);
+        expect_type("'foo bar baz' ", IonType::Symbol);
+        expect_type("2021T ", IonType::Timestamp);
+        expect_type("2021-02T ", IonType::Timestamp);
+        expect_type("2021-02-08T ", IonType::Timestamp);
+        expect_type("2021-02-08T12:30Z ", IonType::Timestamp);
+        expect_type("2021-02-08T12:30:02-00:00 ", IonType::Timestamp);
+        expect_type("2021-02-08T12:30:02.111-00:00 ", IonType::Timestamp);
+        expect_type("{{\"hello\"}}", IonType::Clob);
+    }
+
+    #[test]
+    fn test_read_top_level_scalars() -> IonResult<()> {
+        let mut cursor = text_cursor("null.int true 5 2.5e0 \"hello\" foo 2021-02-08T");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, true)));
+        assert_eq!(cursor.read_null()?, Some(IonType::Integer));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+        assert_eq!(cursor.read_bool()?, Some(true));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(5));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f64()?, Some(2.5));
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_string()?, Some("hello".to_string()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(cursor.read_raw_symbol()?, Some(RawSymbolToken::Text("foo")));
+        // The final value is followed by the end of the stream rather than a stop character
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert_eq!(cursor.next()?, None);
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_decimals_and_timestamps() -> IonResult<()> {
+        let mut cursor = text_cursor("-0d0 2021-02-08T");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(cursor.read_decimal()?, Some(Decimal::negative_zero()));
+        assert_eq!(cursor.read_timestamp()?, None);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert_eq!(
+            cursor.read_timestamp()?,
+            Some(Timestamp::with_ymd(2021, 2, 8).build()?)
+        );
+        assert_eq!(cursor.read_decimal()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_big_integers() -> IonResult<()> {
+        let mut cursor =
+            text_cursor("9223372036854775807 -18446744073709551616 0x1_0000_0000_0000_0000");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::I64));
+        assert_eq!(cursor.read_big_int()?, Some(BigInt::from(i64::MAX)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.integer_size(), Some(IntegerSize::BigInt));
+        assert!(cursor.read_i64().is_err());
+        assert_eq!(cursor.read_big_int()?, Some(-BigInt::from(u64::MAX) - 1));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(
+            cursor.read_any_int()?,
+            Some(AnyInt::BigInt(BigInt::from(u64::MAX) + 1))
+        );
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_in_small_chunks() -> IonResult<()> {
+        let text = "123456 'hello, world' \"naïve café\" 2021-02-08T12:30:02.111-00:00";
+        let mut cursor = TextIonCursor::new(BufReader::with_capacity(1, text.as_bytes()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(123456));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolToken::Text("hello, world"))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_string()?, Some("naïve café".to_string()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrong_type_reads_return_none() -> IonResult<()> {
+        let mut cursor = text_cursor("5");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_string()?, None);
+        assert_eq!(cursor.read_bool()?, None);
+        assert_eq!(cursor.read_null()?, None);
+        assert_eq!(cursor.read_i64()?, Some(5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_stream() -> IonResult<()> {
+        let mut cursor = text_cursor(" \n\t ");
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed_input_is_an_error() {
+        let mut cursor = text_cursor("5 ]");
+        assert_eq!(cursor.next(), Ok(Some(Value(IonType::Integer, false))));
+        match cursor.next() {
+            Err(IonError::DecodingError { .. }) => {}
+            other => panic!("Expected a decoding error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unterminated_value_is_an_error() {
+        let mut cursor = text_cursor("\"hello");
+        match cursor.next() {
+            Err(IonError::DecodingError { .. }) => {}
+            other => panic!("Expected a decoding error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_illegal_step_in_and_step_out() -> IonResult<()> {
+        let mut cursor = text_cursor("5");
+        assert!(cursor.step_out().is_err());
+        cursor.next()?;
+        assert!(cursor.step_in().is_err());
+        assert_eq!(cursor.depth(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_reader() -> IonResult<()> {
+        let mut reader = Reader::new(text_cursor("foo \"bar\" 7"));
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.read_symbol()?, Some("foo".to_string()));
+        assert_eq!(reader.next()?, Some((IonType::String, false)));
+        assert_eq!(reader.read_string()?, Some("bar".to_string()));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(7));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_traverse_containers() -> IonResult<()> {
+        let mut cursor = text_cursor("[1, (a + b), {foo: \"bar\", 'baz qux': [true,]}] 7");
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.depth(), 1);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::SExpression, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(cursor.read_raw_symbol()?, Some(RawSymbolToken::Text("a")));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(cursor.read_raw_symbol()?, Some(RawSymbolToken::Text("+")));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(cursor.read_raw_symbol()?, Some(RawSymbolToken::Text("b")));
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.depth(), 2);
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.raw_field_name(), Some(RawSymbolToken::Text("foo")));
+        assert_eq!(cursor.read_string()?, Some("bar".to_string()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        assert_eq!(
+            cursor.raw_field_name(),
+            Some(RawSymbolToken::Text("baz qux"))
+        );
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+        assert_eq!(cursor.raw_field_name(), None);
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.depth(), 0);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(7));
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_containers() -> IonResult<()> {
+        let mut cursor = text_cursor("[1, [2, 3], {a: (4 5)}] {b: 6, c: [7]} 8");
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        // Skip over the list without stepping in
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        // Step out before reaching the end of the struct
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(8));
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbol_id_field_names() -> IonResult<()> {
+        let mut cursor = text_cursor("{$10: 1}");
+        cursor.next()?;
+        cursor.step_in()?;
+        cursor.next()?;
+        assert_eq!(cursor.field_id(), Some(10));
+        assert_eq!(cursor.raw_field_name(), Some(RawSymbolToken::SymbolId(10)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_annotations() -> IonResult<()> {
+        let mut cursor = text_cursor("foo::'bar baz'::$10::5 [a::1, (b::c)] {d: e::f::true} 7");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        let annotations: Vec<RawSymbolToken> = cursor.raw_annotations().collect();
+        assert_eq!(
+            annotations,
+            vec![
+                RawSymbolToken::Text("foo"),
+                RawSymbolToken::Text("bar baz"),
+                RawSymbolToken::SymbolId(10),
+            ]
+        );
+        // Only annotations written as symbol IDs are visible through annotation_ids()
+        assert_eq!(cursor.annotation_ids(), &[10]);
+        assert_eq!(cursor.read_i64()?, Some(5));
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        assert_eq!(cursor.raw_annotations().count(), 0);
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        let annotations: Vec<RawSymbolToken> = cursor.raw_annotations().collect();
+        assert_eq!(annotations, vec![RawSymbolToken::Text("a")]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::SExpression, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        let annotations: Vec<RawSymbolToken> = cursor.raw_annotations().collect();
+        assert_eq!(annotations, vec![RawSymbolToken::Text("b")]);
+        assert_eq!(cursor.read_raw_symbol()?, Some(RawSymbolToken::Text("c")));
+        cursor.step_out()?;
+        cursor.step_out()?;
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+        assert_eq!(cursor.raw_field_name(), Some(RawSymbolToken::Text("d")));
+        let annotations: Vec<RawSymbolToken> = cursor.raw_annotations().collect();
+        assert_eq!(
+            annotations,
+            vec![RawSymbolToken::Text("e"), RawSymbolToken::Text("f")]
+        );
+        cursor.step_out()?;
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.raw_annotations().count(), 0);
+        assert_eq!(cursor.annotation_ids(), &[] as &[usize]);
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reader_annotations() -> IonResult<()> {
+        let mut reader = Reader::new(text_cursor("foo::$4::bar::5"));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        let annotations: Vec<&str> = reader.annotations().collect::<IonResult<_>>()?;
+        assert_eq!(annotations, vec!["foo", "name", "bar"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_comments() -> IonResult<()> {
+        let text = "// leading\n1 /* block\ncomment */ [2, // inside\n 3 /* end */] 4 // trailing";
+        let mut cursor = text_cursor(text);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(3));
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(4));
+        assert_eq!(cursor.next()?, None);
+
+        // Comments can immediately follow a value
+        let mut cursor = text_cursor("{port: 8080// default\n} [1/*x*/]");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.raw_field_name(), Some(RawSymbolToken::Text("port")));
+        assert_eq!(cursor.read_i64()?, Some(8080));
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+
+        // A block comment that is never closed is an error
+        let mut cursor = text_cursor("1 /* unterminated");
+        cursor.next()?;
+        assert!(cursor.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ion_version_markers() -> IonResult<()> {
+        let mut cursor = text_cursor("$ion_1_0 1 '$ion_1_0' $ion_1_0::2 [$ion_1_0]");
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.ion_version(), (1, 0));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        // A quoted symbol is never a version marker
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        // An annotation is never a version marker
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        // A version marker can only appear at the top level
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolToken::Text("$ion_1_0"))
+        );
+
+        let mut cursor = text_cursor("$ion_2_0 1");
+        assert!(cursor.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_symbol_ids() -> IonResult<()> {
+        let mut cursor = text_cursor("$10 '$10' foo");
+        cursor.next()?;
+        assert_eq!(cursor.read_symbol_id()?, Some(10));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolToken::SymbolId(10))
+        );
+        cursor.next()?;
+        assert!(cursor.read_symbol_id().is_err());
+        assert_eq!(cursor.read_raw_symbol()?, Some(RawSymbolToken::Text("$10")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reader_local_symbol_tables() -> IonResult<()> {
+        let text = r#"
+            $ion_1_0
+            $ion_symbol_table::{
+                symbols: ["foo", "bar"], // $10, $11
+            }
+            $10
+            $ion_symbol_table::{
+                imports: $ion_symbol_table,
+                symbols: ["baz"], // $12
+            }
+            {$11: $12::$3}
+            $ion_1_0
+            $10
+        "#;
+        let mut reader = Reader::new(text_cursor(text));
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.read_symbol()?, Some("foo".to_string()));
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.field_name(), Some("bar"));
+        let annotations: Vec<&str> = reader.annotations().collect::<IonResult<_>>()?;
+        assert_eq!(annotations, vec!["baz"]);
+        assert_eq!(reader.read_symbol()?, Some("$ion_symbol_table".to_string()));
+        reader.step_out()?;
+        // The version marker resets the symbol table, so $10 is no longer defined
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert!(reader.read_symbol().is_err());
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_containers_in_small_chunks() -> IonResult<()> {
+        let text = "{foo: [1, 2], bar: '''long''' '''string'''}";
+        let mut cursor = TextIonCursor::new(BufReader::with_capacity(1, text.as_bytes()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.raw_field_name(), Some(RawSymbolToken::Text("bar")));
+        assert_eq!(cursor.read_string()?, Some("longstring".to_string()));
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_long_string_at_end_of_stream() -> IonResult<()> {
+        let mut cursor = text_cursor("'''hello'''");
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_string()?, Some("hello".to_string()));
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed_containers_are_errors() {
+        // Reads every value in the stream, stepping into each container, until an error occurs.
+        let read_all = |text: &str| -> IonResult<()> {
+            let mut cursor = text_cursor(text);
+            loop {
+                match cursor.next()? {
+                    Some(Value(ion_type, false)) if ion_type.is_container() => cursor.step_in()?,
+                    Some(_) => {}
+                    None if cursor.depth() > 0 => cursor.step_out()?,
+                    None => return Ok(()),
+                }
+            }
+        };
+        assert_eq!(read_all("[1, (2), {a: 3}]"), Ok(()));
+        assert!(read_all("[1 2]").is_err());
+        assert!(read_all("[1,,2]").is_err());
+        assert!(read_all("[1)").is_err());
+        assert!(read_all("(1]").is_err());
+        assert!(read_all("{a:1 b:2}").is_err());
+        assert!(read_all("{1}").is_err());
+        assert!(read_all("[1, 2").is_err());
+        assert!(read_all("{a: [1]").is_err());
+        assert!(read_all("{a: [1] b: 2}").is_err());
+        assert!(read_all("[foo::]").is_err());
+        assert!(read_all("foo::").is_err());
+    }
+}
@@ -1,10 +1,51 @@
+pub(crate) mod cursor;
 pub(in crate::text) mod parsers;
-pub mod reader;
 pub mod writer;
 
+use crate::cursor::RawSymbolToken;
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
+use crate::types::SymbolId;
 use crate::IonType;
+use num_bigint::BigInt;
+
+/// A symbol token as it appears in a text Ion stream. Symbols can be written out as text
+/// (`foo`, `'foo bar'`) or refer to an entry in the current symbol table by ID (`$10`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TextSymbolToken {
+    Text(String),
+    SymbolId(SymbolId),
+}
+
+impl TextSymbolToken {
+    /// Returns a [RawSymbolToken] that borrows this token's text (if any).
+    pub fn as_raw_symbol_token(&self) -> RawSymbolToken {
+        match self {
+            TextSymbolToken::Text(text) => RawSymbolToken::Text(text.as_str()),
+            TextSymbolToken::SymbolId(sid) => RawSymbolToken::SymbolId(*sid),
+        }
+    }
+}
+
+/// A [TextStreamItem] along with any annotations that preceded it in the stream (e.g. the `foo`
+/// and `bar` in `foo::bar::5`).
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AnnotatedTextStreamItem {
+    pub annotations: Vec<TextSymbolToken>,
+    pub item: TextStreamItem,
+}
+
+impl AnnotatedTextStreamItem {
+    pub fn new(annotations: Vec<TextSymbolToken>, item: TextStreamItem) -> Self {
+        AnnotatedTextStreamItem { annotations, item }
+    }
+}
+
+impl From<TextStreamItem> for AnnotatedTextStreamItem {
+    fn from(item: TextStreamItem) -> Self {
+        AnnotatedTextStreamItem::new(Vec::new(), item)
+    }
+}
 
 /// Represents a single item encountered in a text Ion stream. The enum includes variants for each
 /// scalar type as well as variants for the beginning and end of each container type.
@@ -13,14 +54,15 @@ pub(crate) enum TextStreamItem {
     Null(IonType),
     Boolean(bool),
     Integer(i64),
+    // An integer that is too large to fit in an i64.
+    BigInteger(BigInt),
     Float(f64),
     Decimal(Decimal),
     Timestamp(Timestamp),
-    // TODO: String(&str) will be possible if/when we add reusable buffers to the TextReader.
+    // TODO: String(&str) will be possible if/when we add reusable buffers to the TextIonCursor.
     String(String),
-    // TODO: This will be a Symbol(SymbolToken) when the SymbolToken API is implemented.
-    Symbol(String),
-    // TODO: [BC]lob(&[u8]) will be possible if/when we add reusable buffers to the TextReader.
+    Symbol(TextSymbolToken),
+    // TODO: [BC]lob(&[u8]) will be possible if/when we add reusable buffers to the TextIonCursor.
     Blob(Vec<u8>),
     Clob(Vec<u8>),
     ListStart,
@@ -29,16 +71,18 @@ pub(crate) enum TextStreamItem {
     SExpressionEnd,
     StructStart,
     StructEnd,
+    // An Ion version marker (e.g. `$ion_1_0`) at the top level of the stream.
+    VersionMarker(u8, u8),
 }
 
 impl TextStreamItem {
     /// Returns the IonType associated with the TextStreamItem in question. If the TextStreamItem
-    /// represents the end of a container, [ion_type] will return [None].
+    /// represents the end of a container or an Ion version marker, [ion_type] will return [None].
     pub fn ion_type(&self) -> Option<IonType> {
         let ion_type = match self {
             TextStreamItem::Null(ion_type) => *ion_type,
             TextStreamItem::Boolean(_) => IonType::Boolean,
-            TextStreamItem::Integer(_) => IonType::Integer,
+            TextStreamItem::Integer(_) | TextStreamItem::BigInteger(_) => IonType::Integer,
             TextStreamItem::Float(_) => IonType::Float,
             TextStreamItem::Decimal(_) => IonType::Decimal,
             TextStreamItem::Timestamp(_) => IonType::Timestamp,
@@ -49,8 +93,25 @@ impl TextStreamItem {
             TextStreamItem::ListStart => IonType::List,
             TextStreamItem::SExpressionStart => IonType::SExpression,
             TextStreamItem::StructStart => IonType::Struct,
-            _ => return None, // The remaining items are container ends
+            _ => return None, // The remaining items are container ends and version markers
         };
         Some(ion_type)
     }
+
+    /// Returns true if the TextStreamItem represents a null of any type.
+    pub fn is_null(&self) -> bool {
+        if let TextStreamItem::Null(_) = self {
+            return true;
+        }
+        false
+    }
+
+    /// Returns true if the TextStreamItem represents the beginning of a (non-null) container.
+    pub fn is_container_start(&self) -> bool {
+        use TextStreamItem::*;
+        match self {
+            ListStart | SExpressionStart | StructStart => true,
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,86 @@
+//! Parsing logic for the annotations that can precede any value (e.g. the `foo` in `foo::5`).
+
+use nom::bytes::streaming::tag;
+use nom::combinator::{map, opt};
+use nom::multi::many0;
+use nom::sequence::{pair, terminated, tuple};
+use nom::IResult;
+
+use crate::text::parsers::symbol::parse_symbol_token;
+use crate::text::parsers::whitespace;
+use crate::text::{AnnotatedTextStreamItem, TextStreamItem, TextSymbolToken};
+
+/// Matches a single annotation (an identifier, quoted symbol, or symbol ID) and the `::` that
+/// follows it, along with any surrounding whitespace.
+pub(crate) fn parse_annotation(input: &str) -> IResult<&str, TextSymbolToken> {
+    terminated(
+        parse_symbol_token,
+        tuple((opt(whitespace), tag("::"), opt(whitespace))),
+    )(input)
+}
+
+/// Matches zero or more annotations followed by a value matched by the provided parser.
+pub(crate) fn annotated<'a, P>(
+    value_parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, AnnotatedTextStreamItem>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, TextStreamItem>,
+{
+    map(
+        pair(many0(parse_annotation), value_parser),
+        |(annotations, item)| AnnotatedTextStreamItem::new(annotations, item),
+    )
+}
+
+#[cfg(test)]
+mod annotation_parsing_tests {
+    use crate::text::parsers::annotations::{annotated, parse_annotation};
+    use crate::text::parsers::containers::stream_item;
+    use crate::text::{AnnotatedTextStreamItem, TextStreamItem, TextSymbolToken};
+
+    fn text(text: &str) -> TextSymbolToken {
+        TextSymbolToken::Text(text.to_owned())
+    }
+
+    fn parse_equals(input: &str, annotations: Vec<TextSymbolToken>, item: TextStreamItem) {
+        let (_remaining, actual) = annotated(stream_item)(input).unwrap();
+        assert_eq!(actual, AnnotatedTextStreamItem::new(annotations, item));
+    }
+
+    #[test]
+    fn test_parse_annotation() {
+        assert_eq!(parse_annotation("foo::5 ").unwrap().1, text("foo"));
+        assert_eq!(parse_annotation("foo :: 5 ").unwrap().1, text("foo"));
+        assert_eq!(
+            parse_annotation("'foo bar'::5 ").unwrap().1,
+            text("foo bar")
+        );
+        assert_eq!(
+            parse_annotation("$10::5 ").unwrap().1,
+            TextSymbolToken::SymbolId(10)
+        );
+        // A single colon is not an annotation delimiter
+        assert!(parse_annotation("foo:5 ").is_err());
+        // Strings cannot be used as annotations
+        assert!(parse_annotation("\"foo\"::5 ").is_err());
+    }
+
+    #[test]
+    fn test_parse_annotated_values() {
+        parse_equals("5 ", vec![], TextStreamItem::Integer(5));
+        parse_equals("foo::5 ", vec![text("foo")], TextStreamItem::Integer(5));
+        parse_equals(
+            "foo::'bar baz'::$10::5 ",
+            vec![text("foo"), text("bar baz"), TextSymbolToken::SymbolId(10)],
+            TextStreamItem::Integer(5),
+        );
+        parse_equals(
+            "foo :: bar ",
+            vec![text("foo")],
+            TextStreamItem::Symbol(text("bar")),
+        );
+        parse_equals("foo::[1] ", vec![text("foo")], TextStreamItem::ListStart);
+        // A symbol that is not followed by `::` is a value rather than an annotation
+        parse_equals("foo bar ", vec![], TextStreamItem::Symbol(text("foo")));
+    }
+}
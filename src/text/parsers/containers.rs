@@ -0,0 +1,393 @@
+//! Parsing logic for the structure of a text Ion stream: the beginning and end of each
+//! container type, the delimiters that separate container values, and struct field names.
+
+use nom::branch::alt;
+use nom::bytes::streaming::tag;
+use nom::character::streaming::char;
+use nom::combinator::{map, map_opt, not, opt, peek, value};
+use nom::sequence::{pair, preceded, terminated, tuple};
+use nom::IResult;
+
+use crate::text::parsers::annotations::annotated;
+use crate::text::parsers::blob::parse_blob;
+use crate::text::parsers::boolean::parse_boolean;
+use crate::text::parsers::clob::parse_clob;
+use crate::text::parsers::decimal::parse_decimal;
+use crate::text::parsers::float::parse_float;
+use crate::text::parsers::integer::parse_integer;
+use crate::text::parsers::null::parse_null;
+use crate::text::parsers::string::parse_string;
+use crate::text::parsers::symbol::{
+    parse_ion_version_marker, parse_operator, parse_sexp_identifier, parse_symbol,
+    parse_symbol_token,
+};
+use crate::text::parsers::timestamp::parse_timestamp;
+use crate::text::parsers::whitespace;
+use crate::text::{AnnotatedTextStreamItem, TextStreamItem, TextSymbolToken};
+
+/// Matches any scalar value.
+pub(crate) fn scalar(input: &str) -> IResult<&str, TextStreamItem> {
+    alt((
+        parse_null,
+        parse_boolean,
+        parse_integer,
+        parse_float,
+        parse_decimal,
+        parse_timestamp,
+        parse_string,
+        parse_symbol,
+        parse_blob,
+        parse_clob,
+    ))(input)
+}
+
+/// Matches the beginning of a list, s-expression, or struct.
+pub(crate) fn container_start(input: &str) -> IResult<&str, TextStreamItem> {
+    alt((parse_list_start, parse_sexp_start, parse_struct_start))(input)
+}
+
+/// Matches any scalar value or the beginning of a container.
+pub(crate) fn stream_item(input: &str) -> IResult<&str, TextStreamItem> {
+    alt((scalar, container_start))(input)
+}
+
+/// Matches a (possibly annotated) value or an Ion version marker at the top level of the stream,
+/// discarding any leading whitespace.
+pub(crate) fn top_level_value(input: &str) -> IResult<&str, AnnotatedTextStreamItem> {
+    preceded(
+        opt(whitespace),
+        alt((
+            map(ion_version_marker, AnnotatedTextStreamItem::from),
+            annotated(stream_item),
+        )),
+    )(input)
+}
+
+/// Matches an Ion version marker. `$ion_1_0` is only a version marker if it is not being used
+/// as an annotation.
+fn ion_version_marker(input: &str) -> IResult<&str, TextStreamItem> {
+    terminated(
+        parse_ion_version_marker,
+        not(pair(opt(whitespace), tag("::"))),
+    )(input)
+}
+
+/// Matches the next item inside of a list: either a (possibly annotated) value or the end of the
+/// list. Scalar values are matched along with the delimiter that follows them. The delimiter
+/// following a nested container can only be matched (using [list_delimiter]) after the
+/// container's end.
+pub(crate) fn list_value(input: &str) -> IResult<&str, AnnotatedTextStreamItem> {
+    preceded(
+        opt(whitespace),
+        alt((
+            map(parse_list_end, AnnotatedTextStreamItem::from),
+            annotated(alt((terminated(scalar, list_delimiter), container_start))),
+        )),
+    )(input)
+}
+
+/// Matches the delimiter that must follow each value in a list: either a comma or the end of the
+/// list. The end of the list is not consumed.
+pub(crate) fn list_delimiter(input: &str) -> IResult<&str, ()> {
+    preceded(
+        opt(whitespace),
+        alt((value((), char(',')), value((), peek(char(']'))))),
+    )(input)
+}
+
+/// Matches the next item inside of an s-expression: either a (possibly annotated) value or the
+/// end of the s-expression. S-expression values are not delimited by commas and may include
+/// operators like `+` or `<=`.
+pub(crate) fn sexp_value(input: &str) -> IResult<&str, AnnotatedTextStreamItem> {
+    preceded(
+        opt(whitespace),
+        alt((
+            map(parse_sexp_end, AnnotatedTextStreamItem::from),
+            annotated(alt((
+                scalar,
+                parse_sexp_identifier,
+                parse_operator,
+                container_start,
+            ))),
+        )),
+    )(input)
+}
+
+/// Matches the next item inside of a struct: either a field (a field name and its possibly
+/// annotated value) or the end of the struct. Scalar values are matched along with the delimiter
+/// that follows them. The delimiter following a nested container can only be matched (using
+/// [struct_delimiter]) after the container's end.
+pub(crate) fn struct_field(
+    input: &str,
+) -> IResult<&str, (Option<TextSymbolToken>, AnnotatedTextStreamItem)> {
+    preceded(
+        opt(whitespace),
+        alt((
+            map(parse_struct_end, |struct_end| (None, struct_end.into())),
+            map(
+                pair(
+                    field_name,
+                    annotated(alt((terminated(scalar, struct_delimiter), container_start))),
+                ),
+                |(field_name, value)| (Some(field_name), value),
+            ),
+        )),
+    )(input)
+}
+
+/// Matches the delimiter that must follow each field in a struct: either a comma or the end of the
+/// struct. The end of the struct is not consumed.
+pub(crate) fn struct_delimiter(input: &str) -> IResult<&str, ()> {
+    preceded(
+        opt(whitespace),
+        alt((value((), char(',')), value((), peek(char('}'))))),
+    )(input)
+}
+
+/// Matches a struct field name and the `:` that follows it. Field names can be written as an
+/// identifier (`foo`), a quoted symbol (`'foo'`), a symbol ID (`$10`), or a string (`"foo"`).
+fn field_name(input: &str) -> IResult<&str, TextSymbolToken> {
+    terminated(
+        alt((
+            map_opt(parse_string, |item| match item {
+                TextStreamItem::String(text) => Some(TextSymbolToken::Text(text)),
+                _ => None,
+            }),
+            parse_symbol_token,
+        )),
+        tuple((opt(whitespace), char(':'), opt(whitespace))),
+    )(input)
+}
+
+fn parse_list_start(input: &str) -> IResult<&str, TextStreamItem> {
+    value(TextStreamItem::ListStart, char('['))(input)
+}
+
+fn parse_list_end(input: &str) -> IResult<&str, TextStreamItem> {
+    value(TextStreamItem::ListEnd, char(']'))(input)
+}
+
+fn parse_sexp_start(input: &str) -> IResult<&str, TextStreamItem> {
+    value(TextStreamItem::SExpressionStart, char('('))(input)
+}
+
+fn parse_sexp_end(input: &str) -> IResult<&str, TextStreamItem> {
+    value(TextStreamItem::SExpressionEnd, char(')'))(input)
+}
+
+/// Matches the beginning of a struct. A `{` followed immediately by another `{` is the beginning of
+/// a blob or clob rather than a struct.
+fn parse_struct_start(input: &str) -> IResult<&str, TextStreamItem> {
+    value(
+        TextStreamItem::StructStart,
+        terminated(char('{'), not(char('{'))),
+    )(input)
+}
+
+fn parse_struct_end(input: &str) -> IResult<&str, TextStreamItem> {
+    value(TextStreamItem::StructEnd, char('}'))(input)
+}
+
+#[cfg(test)]
+mod container_parsing_tests {
+    use crate::text::parsers::containers::{
+        list_delimiter, list_value, sexp_value, stream_item, struct_delimiter, struct_field,
+        top_level_value,
+    };
+    use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok};
+    use crate::text::{AnnotatedTextStreamItem, TextStreamItem, TextSymbolToken};
+    use nom::IResult;
+
+    fn text(text: &str) -> TextSymbolToken {
+        TextSymbolToken::Text(text.to_owned())
+    }
+
+    fn symbol(text: &str) -> TextStreamItem {
+        TextStreamItem::Symbol(TextSymbolToken::Text(text.to_owned()))
+    }
+
+    // Discards the annotations on the next list value so the result can be used with parse_test_ok
+    fn list_item(input: &str) -> IResult<&str, TextStreamItem> {
+        list_value(input).map(|(remaining, value)| (remaining, value.item))
+    }
+
+    // Discards the annotations on the next s-expression value so the result can be used with
+    // parse_test_ok
+    fn sexp_item(input: &str) -> IResult<&str, TextStreamItem> {
+        sexp_value(input).map(|(remaining, value)| (remaining, value.item))
+    }
+
+    #[test]
+    fn test_parse_container_starts() {
+        parse_test_ok(stream_item, "[1]", TextStreamItem::ListStart);
+        parse_test_ok(stream_item, "(a)", TextStreamItem::SExpressionStart);
+        parse_test_ok(stream_item, "{a:1}", TextStreamItem::StructStart);
+        parse_test_ok(stream_item, "{}", TextStreamItem::StructStart);
+        // Blobs and clobs are not mistaken for structs
+        parse_test_ok(
+            stream_item,
+            "{{ZW5jb2RlZA==}} ",
+            TextStreamItem::Blob(Vec::from("encoded".as_bytes())),
+        );
+    }
+
+    #[test]
+    fn test_parse_top_level_values() {
+        let parse_equals = |input: &str, expected: AnnotatedTextStreamItem| {
+            assert_eq!(top_level_value(input).unwrap().1, expected);
+        };
+        parse_equals(" $ion_1_0 ", TextStreamItem::VersionMarker(1, 0).into());
+        parse_equals("/* comment */ 5 ", TextStreamItem::Integer(5).into());
+        // A quoted `$ion_1_0` is a symbol rather than a version marker
+        parse_equals("'$ion_1_0' ", symbol("$ion_1_0").into());
+        // An annotation is not a version marker
+        parse_equals(
+            "$ion_1_0 :: 5 ",
+            AnnotatedTextStreamItem::new(vec![text("$ion_1_0")], TextStreamItem::Integer(5)),
+        );
+    }
+
+    #[test]
+    fn test_parse_list_values() {
+        parse_test_ok(list_item, " 1, 2]", TextStreamItem::Integer(1));
+        parse_test_ok(list_item, "1]", TextStreamItem::Integer(1));
+        parse_test_ok(list_item, "foo ,2]", symbol("foo"));
+        parse_test_ok(list_item, " ]", TextStreamItem::ListEnd);
+        parse_test_ok(list_item, "[1], 2]", TextStreamItem::ListStart);
+
+        // Scalars must be followed by a comma or the end of the list
+        parse_test_err(list_item, "1 2]");
+        // A comma cannot appear before the first value
+        parse_test_err(list_item, ", 1]");
+        // Comments can appear anywhere whitespace can
+        parse_test_ok(
+            list_item,
+            "/* a */ 1 // b\n, 2]",
+            TextStreamItem::Integer(1),
+        );
+        // Comments can immediately follow a value
+        parse_test_ok(list_item, "1/*x*/]", TextStreamItem::Integer(1));
+        parse_test_ok(list_item, "foo// b\n]", symbol("foo"));
+        // Lists must be closed with a ']'
+        parse_test_err(list_item, ")");
+    }
+
+    #[test]
+    fn test_parse_annotated_list_values() {
+        let parse_equals = |input: &str, expected: AnnotatedTextStreamItem| {
+            assert_eq!(list_value(input).unwrap().1, expected);
+        };
+        parse_equals(
+            "foo::1, 2]",
+            AnnotatedTextStreamItem::new(vec![text("foo")], TextStreamItem::Integer(1)),
+        );
+        parse_equals(
+            " foo :: $10 :: [1]]",
+            AnnotatedTextStreamItem::new(
+                vec![text("foo"), TextSymbolToken::SymbolId(10)],
+                TextStreamItem::ListStart,
+            ),
+        );
+        parse_equals("]", TextStreamItem::ListEnd.into());
+        // The end of a list cannot be annotated
+        assert!(list_value("foo::]").is_err());
+    }
+
+    #[test]
+    fn test_parse_list_delimiters() {
+        assert_eq!(list_delimiter(" , 2]"), Ok((" 2]", ())));
+        assert_eq!(list_delimiter(" ]"), Ok(("]", ())));
+        assert!(list_delimiter(" 2]").is_err());
+    }
+
+    #[test]
+    fn test_parse_sexp_values() {
+        let parse_symbol_equals =
+            |text: &str, expected: &str| parse_test_ok(sexp_item, text, symbol(expected));
+        parse_symbol_equals("a+b)", "a");
+        parse_symbol_equals("+b)", "+");
+        parse_symbol_equals(" <= 1)", "<=");
+        parse_symbol_equals("foo bar)", "foo");
+        parse_test_ok(sexp_item, "-1)", TextStreamItem::Integer(-1));
+        parse_test_ok(sexp_item, "1 2)", TextStreamItem::Integer(1));
+        parse_test_ok(sexp_item, " )", TextStreamItem::SExpressionEnd);
+        parse_test_ok(sexp_item, "(a))", TextStreamItem::SExpressionStart);
+
+        // Commas are not legal s-expression delimiters
+        parse_test_err(sexp_item, ", 1)");
+        // S-expressions must be closed with a ')'
+        parse_test_err(sexp_item, "]");
+    }
+
+    #[test]
+    fn test_parse_annotated_sexp_values() {
+        assert_eq!(
+            sexp_value("foo::bar+baz)").unwrap().1,
+            AnnotatedTextStreamItem::new(vec![text("foo")], symbol("bar"))
+        );
+        assert_eq!(
+            sexp_value("'a b'::(c))").unwrap().1,
+            AnnotatedTextStreamItem::new(vec![text("a b")], TextStreamItem::SExpressionStart)
+        );
+    }
+
+    #[test]
+    fn test_parse_struct_fields() {
+        let parse_field_equals = |text: &str, name: TextSymbolToken, item: TextStreamItem| {
+            assert_eq!(struct_field(text).unwrap().1, (Some(name), item.into()));
+        };
+        parse_field_equals("foo: 1}", text("foo"), TextStreamItem::Integer(1));
+        parse_field_equals(
+            " foo : 1 , bar: 2}",
+            text("foo"),
+            TextStreamItem::Integer(1),
+        );
+        parse_field_equals("'foo bar':1}", text("foo bar"), TextStreamItem::Integer(1));
+        parse_field_equals("\"foo\":1}", text("foo"), TextStreamItem::Integer(1));
+        parse_field_equals("'''foo''':1}", text("foo"), TextStreamItem::Integer(1));
+        parse_field_equals(
+            "$10:1}",
+            TextSymbolToken::SymbolId(10),
+            TextStreamItem::Integer(1),
+        );
+        parse_field_equals("foo:[1]}", text("foo"), TextStreamItem::ListStart);
+        parse_field_equals(
+            "port: 8080// default\n}",
+            text("port"),
+            TextStreamItem::Integer(8080),
+        );
+        parse_field_equals(
+            "a: 1.5e0/*x*/, b: 2}",
+            text("a"),
+            TextStreamItem::Float(1.5),
+        );
+        assert_eq!(
+            struct_field(" }").unwrap().1,
+            (None, TextStreamItem::StructEnd.into())
+        );
+        assert_eq!(
+            struct_field("foo: bar::baz::1}").unwrap().1,
+            (
+                Some(text("foo")),
+                AnnotatedTextStreamItem::new(
+                    vec![text("bar"), text("baz")],
+                    TextStreamItem::Integer(1)
+                )
+            )
+        );
+
+        // Fields must have a name
+        assert!(struct_field("1}").is_err());
+        // Fields must be followed by a comma or the end of the struct
+        assert!(struct_field("foo:1 bar:2}").is_err());
+        // Structs must be closed with a '}'
+        assert!(struct_field("]").is_err());
+    }
+
+    #[test]
+    fn test_parse_struct_delimiters() {
+        assert_eq!(struct_delimiter(" , b:2}"), Ok((" b:2}", ())));
+        assert_eq!(struct_delimiter("}"), Ok(("}", ())));
+        assert!(struct_delimiter(" b:2}").is_err());
+    }
+}
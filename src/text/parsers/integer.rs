@@ -6,9 +6,10 @@ use nom::bytes::streaming::{is_a, tag, take_while1};
 use nom::character::streaming::char;
 use nom::combinator::{map_res, opt, recognize};
 use nom::multi::many0_count;
-use nom::sequence::{pair, preceded, separated_pair, terminated};
+use nom::sequence::{pair, separated_pair, terminated};
 use nom::IResult;
-use std::num::ParseIntError;
+use num_bigint::{BigInt, ParseBigIntError};
+use num_traits::{Num, ToPrimitive};
 
 // This module uses the phrase "base 10" to avoid potentially confusing references to "decimal",
 // a phrase which is heavily overloaded in the context of parsing Ion. It may refer to the Ion type
@@ -32,11 +33,7 @@ fn base_16_integer(input: &str) -> IResult<&str, TextStreamItem> {
             alt((tag("0x"), tag("0X"))),
             base_16_integer_digits,
         ),
-        |(maybe_sign, text_digits)| {
-            parse_i64_with_radix(text_digits, 16)
-                .map(|i| if maybe_sign.is_some() { -i } else { i })
-                .map(|i| TextStreamItem::Integer(i))
-        },
+        |(maybe_sign, text_digits)| parse_integer_with_radix(maybe_sign.is_some(), text_digits, 16),
     )(input)
 }
 
@@ -65,11 +62,7 @@ fn base_2_integer(input: &str) -> IResult<&str, TextStreamItem> {
             alt((tag("0b"), tag("0B"))),
             base_2_integer_digits,
         ),
-        |(maybe_sign, text_digits)| {
-            parse_i64_with_radix(text_digits, 2)
-                .map(|i| if maybe_sign.is_some() { -i } else { i })
-                .map(|i| TextStreamItem::Integer(i))
-        },
+        |(maybe_sign, text_digits)| parse_integer_with_radix(maybe_sign.is_some(), text_digits, 2),
     )(input)
 }
 
@@ -87,19 +80,40 @@ fn base_2_integer_digits(input: &str) -> IResult<&str, &str> {
 /// [i64] as a [TextStreamItem::Integer].
 fn base_10_integer(input: &str) -> IResult<&str, TextStreamItem> {
     map_res(
-        recognize(preceded(opt(char('-')), base_10_integer_digits)),
-        |text| parse_i64_with_radix(text, 10).map(|i| TextStreamItem::Integer(i)),
+        pair(opt(char('-')), base_10_integer_digits),
+        |(maybe_sign, text_digits)| parse_integer_with_radix(maybe_sign.is_some(), text_digits, 10),
     )(input)
 }
 
-/// Strips any underscores out of the provided text and then parses it according to the specified
-/// radix.
-fn parse_i64_with_radix(text: &str, radix: u32) -> Result<i64, ParseIntError> {
-    if text.contains('_') {
-        let sanitized = text.replace("_", "");
-        return i64::from_str_radix(&sanitized, radix);
+/// Strips any underscores out of the provided digits and then parses them according to the
+/// specified radix. Integers that are too large to fit in an i64 are returned as a
+/// [TextStreamItem::BigInteger].
+fn parse_integer_with_radix(
+    is_negative: bool,
+    text_digits: &str,
+    radix: u32,
+) -> Result<TextStreamItem, ParseBigIntError> {
+    let sanitized;
+    let digits = if text_digits.contains('_') {
+        sanitized = text_digits.replace("_", "");
+        sanitized.as_str()
+    } else {
+        text_digits
+    };
+
+    // The digits have already been validated, so parsing them as an i64 can only fail if the
+    // magnitude is too large.
+    if let Ok(magnitude) = i64::from_str_radix(digits, radix) {
+        let value = if is_negative { -magnitude } else { magnitude };
+        return Ok(TextStreamItem::Integer(value));
+    }
+    let magnitude = BigInt::from_str_radix(digits, radix)?;
+    let value = if is_negative { -magnitude } else { magnitude };
+    // i64::MIN's magnitude is too large to fit in an i64, but the negated value is not.
+    match value.to_i64() {
+        Some(value) => Ok(TextStreamItem::Integer(value)),
+        None => Ok(TextStreamItem::BigInteger(value)),
     }
-    i64::from_str_radix(text, radix)
 }
 
 #[cfg(test)]
@@ -107,15 +121,36 @@ mod integer_parsing_tests {
     use crate::text::parsers::integer::parse_integer;
     use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok};
     use crate::text::TextStreamItem;
+    use num_bigint::BigInt;
+    use std::str::FromStr;
 
     fn parse_equals(text: &str, expected: i64) {
         parse_test_ok(parse_integer, text, TextStreamItem::Integer(expected))
     }
 
+    fn parse_equals_big_int(text: &str, expected: &str) {
+        let expected = BigInt::from_str(expected).unwrap();
+        parse_test_ok(parse_integer, text, TextStreamItem::BigInteger(expected))
+    }
+
     fn parse_fails(text: &str) {
         parse_test_err(parse_integer, text)
     }
 
+    #[test]
+    fn test_parse_big_integers() {
+        parse_equals("9223372036854775807 ", i64::MAX);
+        parse_equals("-9223372036854775808 ", i64::MIN);
+        parse_equals("-0x8000_0000_0000_0000 ", i64::MIN);
+        parse_equals_big_int("9223372036854775808 ", "9223372036854775808");
+        parse_equals_big_int("-9223372036854775809 ", "-9223372036854775809");
+        parse_equals_big_int("0xFFFF_FFFF_FFFF_FFFF ", "18446744073709551615");
+        parse_equals_big_int(
+            "-0b1_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000 ",
+            "-18446744073709551616",
+        );
+    }
+
     #[test]
     fn test_parse_base_10_integers() {
         parse_equals("1 ", 1);
@@ -130,6 +165,11 @@ mod integer_parsing_tests {
         parse_fails("--305 ");
         // Doesn't accept a number if it's the last thing in the input (might be incomplete stream)
         parse_fails("305");
+        // Accepts a comment immediately after the number
+        parse_equals("8080// default\n", 8080);
+        parse_equals("1/*x*/ ", 1);
+        // Doesn't accept a single slash after the number
+        parse_fails("1/2 ");
     }
 
     #[test]
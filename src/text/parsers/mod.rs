@@ -3,14 +3,19 @@
 
 use std::str::FromStr;
 
-use nom::bytes::streaming::is_a;
+use nom::branch::alt;
+use nom::bytes::streaming::{is_a, tag, take_till, take_until};
 use nom::character::streaming::{one_of, satisfy};
-use nom::combinator::peek;
+use nom::combinator::{peek, recognize, value};
+use nom::multi::many1_count;
+use nom::sequence::{pair, tuple};
 use nom::IResult;
 
+pub(crate) mod annotations;
 pub(crate) mod blob;
 pub(crate) mod boolean;
 pub(crate) mod clob;
+pub(crate) mod containers;
 pub(crate) mod decimal;
 pub(crate) mod float;
 pub(crate) mod integer;
@@ -25,9 +30,13 @@ pub(crate) mod timestamp;
 
 /// Matches (but does not consume) the next character in the input stream if it is one of the Ion
 /// stop characters. These characters must follow several different Ion text encodings, including
-/// integers, floats, decimals, and timestamps.
+/// integers, floats, decimals, and timestamps. The start of a comment (`//` or `/*`) also acts as
+/// a stop character, in which case `/` is returned.
 pub(crate) fn stop_character(input: &str) -> IResult<&str, char> {
-    peek(one_of("{}[](),\"' \t\n\r\u{0b}\u{0c}"))(input)
+    peek(alt((
+        one_of("{}[](),\"' \t\n\r\u{0b}\u{0c}"),
+        value('/', alt((tag("//"), tag("/*")))),
+    )))(input)
 }
 
 /// Takes a numeric string and removes all leading zeros. If the string is entirely zeros
@@ -63,9 +72,57 @@ pub(crate) fn digit(input: &str) -> IResult<&str, char> {
     satisfy(|c| c.is_digit(10))(input)
 }
 
-/// Matches one or more whitespace characters.
+/// Matches one or more whitespace characters and/or comments.
 pub(crate) fn whitespace(input: &str) -> IResult<&str, &str> {
-    is_a(" \r\n\t")(input)
+    recognize(many1_count(alt((
+        is_a(" \r\n\t\u{0b}\u{0c}"),
+        line_comment,
+        block_comment,
+    ))))(input)
+}
+
+/// Matches a comment that begins with `//` and continues to the end of the line. The newline
+/// itself is not consumed.
+fn line_comment(input: &str) -> IResult<&str, &str> {
+    recognize(pair(tag("//"), take_till(|c| c == '\n' || c == '\r')))(input)
+}
+
+/// Matches a comment that begins with `/*` and ends with `*/`. Block comments can span several
+/// lines but cannot be nested.
+fn block_comment(input: &str) -> IResult<&str, &str> {
+    recognize(tuple((tag("/*"), take_until("*/"), tag("*/"))))(input)
+}
+
+#[cfg(test)]
+mod whitespace_tests {
+    use crate::text::parsers::{stop_character, whitespace};
+
+    #[test]
+    fn test_stop_character() {
+        assert_eq!(stop_character(" 5"), Ok((" 5", ' ')));
+        assert_eq!(stop_character("]"), Ok(("]", ']')));
+        // The start of a comment ends a value without being consumed
+        assert_eq!(stop_character("// comment\n"), Ok(("// comment\n", '/')));
+        assert_eq!(stop_character("/* comment */"), Ok(("/* comment */", '/')));
+        // A single slash is not a stop character
+        assert!(stop_character("/5").is_err());
+        assert!(stop_character("a").is_err());
+    }
+
+    #[test]
+    fn test_parse_whitespace() {
+        assert_eq!(whitespace(" \t\r\n5"), Ok(("5", " \t\r\n")));
+        assert_eq!(whitespace("// comment\n5"), Ok(("5", "// comment\n")));
+        assert_eq!(
+            whitespace(" /* multi\nline */ // and more\n 5"),
+            Ok(("5", " /* multi\nline */ // and more\n "))
+        );
+        // A single slash is not a comment
+        assert!(whitespace("/5").is_err());
+        // Comments that have not ended yet are incomplete
+        assert!(whitespace("// comment").is_err());
+        assert!(whitespace("/* comment */ /* comment ").is_err());
+    }
 }
 
 /// Helper functions used in the unit tests for each parsing module.
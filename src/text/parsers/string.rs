@@ -140,6 +140,8 @@ mod string_parsing_tests {
         parse_equals("\"\\u0048ello, \\u0077orld!\" ", "Hello, world!");
         // 8-digit Unicode hex escape sequences
         parse_equals("\"\\U00000048ello, \\U00000077orld!\" ", "Hello, world!");
+        // Surrogate pairs of 4-digit Unicode hex escape sequences
+        parse_equals("\"\\uD83D\\uDE00 \\ud83d\\ude00\" ", "\u{1F600} \u{1F600}");
         // Escaped newlines are discarded
         parse_equals("\"Hello,\\\n world!\" ", "Hello, world!");
 
@@ -149,6 +151,11 @@ mod string_parsing_tests {
         parse_fails("\"Hello, world ");
         // Leading whitespace not accepted
         parse_fails(" \"Hello, world\" ");
+        // Unpaired surrogates
+        parse_fails("\"\\uD83D\" ");
+        parse_fails("\"\\uDE00\" ");
+        parse_fails("\"\\uD83D\\u0041\" ");
+        parse_fails("\"\\uDE00\\uD83D\" ");
     }
 
     #[test]
@@ -1,32 +1,88 @@
 use crate::text::parsers::stop_character;
 use crate::text::parsers::text_support::{escaped_char, escaped_newline, StringFragment};
-use crate::text::TextStreamItem;
+use crate::text::{TextStreamItem, TextSymbolToken};
 use nom::branch::alt;
-use nom::bytes::streaming::is_not;
-use nom::character::streaming::{char, one_of, satisfy};
-use nom::combinator::{map, recognize, verify};
+use nom::bytes::streaming::{is_a, is_not, tag};
+use nom::character::streaming::{char, digit1, one_of, satisfy};
+use nom::combinator::{map, map_res, peek, recognize, verify};
 use nom::multi::{fold_many0, many0_count};
-use nom::sequence::{delimited, pair, terminated};
+use nom::sequence::{delimited, pair, preceded, separated_pair, terminated};
 use nom::IResult;
+use std::num::ParseIntError;
+use std::str::FromStr;
 
-/// Matches the text representation of a symbol value and returns the resulting [String]
-/// as a [TextStreamItem::Symbol].
+/// The characters that can be combined to form an operator (e.g. `+` or `<=`) inside an
+/// s-expression.
+const OPERATOR_CHARACTERS: &str = "!#%&*+-./;<=>?@^`|~";
+
+/// Matches the text representation of a symbol value and returns the resulting [TextSymbolToken]
+/// as a [TextStreamItem::Symbol]. Identifiers of the form `$10` are interpreted as symbol IDs.
 pub(crate) fn parse_symbol(input: &str) -> IResult<&str, TextStreamItem> {
     alt((identifier, quoted_symbol))(input)
 }
 
-/// Matches a quoted symbol (e.g. `'foo bar'`) and returns the resulting [String]
-/// as a [TextStreamItem::Symbol].
-fn quoted_symbol(input: &str) -> IResult<&str, TextStreamItem> {
-    map(
-        delimited(char('\''), quoted_symbol_body, char('\'')),
-        |text| {
-            println!("Symbol text: {:?}", &text);
-            TextStreamItem::Symbol(text)
+/// Matches a symbol that is not required to be followed by a stop character and returns it as a
+/// [TextSymbolToken]. Identifiers of the form `$10` are interpreted as symbol IDs. This is used
+/// for struct field names, which are followed by a `:`.
+pub(crate) fn parse_symbol_token(input: &str) -> IResult<&str, TextSymbolToken> {
+    alt((
+        map_res(identifier_text, symbol_token_from_identifier),
+        map(quoted_symbol_text, TextSymbolToken::Text),
+    ))(input)
+}
+
+/// Matches an operator (e.g. `+`, `<=`, or `!==`) and returns the resulting [TextSymbolToken]
+/// as a [TextStreamItem::Symbol]. Operators are only legal inside of an s-expression.
+pub(crate) fn parse_operator(input: &str) -> IResult<&str, TextStreamItem> {
+    map(is_a(OPERATOR_CHARACTERS), |text: &str| {
+        TextStreamItem::Symbol(TextSymbolToken::Text(text.to_owned()))
+    })(input)
+}
+
+/// Matches an identifier inside of an s-expression and returns the resulting [TextSymbolToken]
+/// as a [TextStreamItem::Symbol]. Unlike elsewhere in the stream, an s-expression identifier can
+/// also be terminated by an operator character (e.g. the `a` in `(a+b)`).
+pub(crate) fn parse_sexp_identifier(input: &str) -> IResult<&str, TextStreamItem> {
+    map_res(
+        terminated(
+            identifier_text,
+            peek(alt((stop_character, one_of(OPERATOR_CHARACTERS)))),
+        ),
+        |text| symbol_token_from_identifier(text).map(TextStreamItem::Symbol),
+    )(input)
+}
+
+/// Matches an Ion version marker (e.g. `$ion_1_0`) and returns its major and minor version as a
+/// [TextStreamItem::VersionMarker]. Callers are responsible for only applying this parser at the
+/// top level of the stream, where a version marker is not annotated.
+pub(crate) fn parse_ion_version_marker(input: &str) -> IResult<&str, TextStreamItem> {
+    map_res(
+        terminated(
+            preceded(tag("$ion_"), separated_pair(digit1, char('_'), digit1)),
+            stop_character,
+        ),
+        |(major, minor)| -> Result<TextStreamItem, ParseIntError> {
+            Ok(TextStreamItem::VersionMarker(
+                u8::from_str(major)?,
+                u8::from_str(minor)?,
+            ))
         },
     )(input)
 }
 
+/// Matches a quoted symbol (e.g. `'foo bar'`) and returns the resulting [TextSymbolToken]
+/// as a [TextStreamItem::Symbol]. Quoted symbols are never interpreted as symbol IDs.
+fn quoted_symbol(input: &str) -> IResult<&str, TextStreamItem> {
+    map(quoted_symbol_text, |text| {
+        TextStreamItem::Symbol(TextSymbolToken::Text(text))
+    })(input)
+}
+
+/// Matches a quoted symbol (e.g. `'foo bar'`) and returns its unescaped text.
+fn quoted_symbol_text(input: &str) -> IResult<&str, String> {
+    delimited(char('\''), quoted_symbol_body, char('\''))(input)
+}
+
 /// Matches the body of a quoted symbol. (The `hello` in `'hello'`.)
 fn quoted_symbol_body(input: &str) -> IResult<&str, String> {
     fold_many0(
@@ -59,16 +115,30 @@ fn quoted_symbol_fragment_without_escaped_text(input: &str) -> IResult<&str, Str
     })(input)
 }
 
-/// Matches an identifier (e.g. `foo`) and returns the resulting [String]
+/// Matches an identifier (e.g. `foo` or `$10`) and returns the resulting [TextSymbolToken]
 /// as a [TextStreamItem::Symbol].
 fn identifier(input: &str) -> IResult<&str, TextStreamItem> {
-    map(
-        recognize(terminated(
-            pair(identifier_initial_character, identifier_trailing_characters),
-            stop_character,
-        )),
-        |text| TextStreamItem::Symbol(text.to_owned()),
-    )(input)
+    map_res(terminated(identifier_text, stop_character), |text| {
+        symbol_token_from_identifier(text).map(TextStreamItem::Symbol)
+    })(input)
+}
+
+/// Matches the text of an identifier without requiring that it be followed by a stop character.
+fn identifier_text(input: &str) -> IResult<&str, &str> {
+    recognize(pair(
+        identifier_initial_character,
+        identifier_trailing_characters,
+    ))(input)
+}
+
+/// Converts the text of an identifier into a [TextSymbolToken]. Identifiers consisting of a `$`
+/// followed by one or more digits (e.g. `$10`) refer to a symbol ID.
+fn symbol_token_from_identifier(text: &str) -> Result<TextSymbolToken, ParseIntError> {
+    let digits = &text[1..];
+    if text.starts_with('$') && !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(TextSymbolToken::SymbolId(usize::from_str(digits)?));
+    }
+    Ok(TextSymbolToken::Text(text.to_owned()))
 }
 
 /// Matches any character that can appear at the start of an identifier.
@@ -86,16 +156,19 @@ fn identifier_trailing_characters(input: &str) -> IResult<&str, &str> {
 
 #[cfg(test)]
 mod symbol_parsing_tests {
-    use crate::text::parsers::symbol::parse_symbol;
+    use crate::text::parsers::symbol::{
+        parse_ion_version_marker, parse_operator, parse_sexp_identifier, parse_symbol,
+        parse_symbol_token,
+    };
     use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok};
-    use crate::text::TextStreamItem;
+    use crate::text::{TextStreamItem, TextSymbolToken};
+
+    fn symbol(text: &str) -> TextStreamItem {
+        TextStreamItem::Symbol(TextSymbolToken::Text(text.to_owned()))
+    }
 
     fn parse_equals(text: &str, expected: &str) {
-        parse_test_ok(
-            parse_symbol,
-            text,
-            TextStreamItem::Symbol(expected.to_owned()),
-        )
+        parse_test_ok(parse_symbol, text, symbol(expected))
     }
 
     fn parse_fails(text: &str) {
@@ -137,4 +210,84 @@ mod symbol_parsing_tests {
         // Cannot be the last thing in input (stream might be incomplete
         parse_fails("foo");
     }
+
+    #[test]
+    fn test_parse_symbol_ids() {
+        let symbol_id = |sid| TextStreamItem::Symbol(TextSymbolToken::SymbolId(sid));
+        parse_test_ok(parse_symbol, "$0 ", symbol_id(0));
+        parse_test_ok(parse_symbol, "$10 ", symbol_id(10));
+        parse_test_ok(parse_sexp_identifier, "$10+", symbol_id(10));
+        // Quoted symbols are never symbol IDs
+        parse_equals("'$10' ", "$10");
+        // Identifiers that merely begin with `$` and digits are not symbol IDs
+        parse_equals("$10a ", "$10a");
+        // Symbol IDs that don't fit in a usize are rejected
+        parse_fails("$99999999999999999999999999 ");
+    }
+
+    #[test]
+    fn test_parse_ion_version_markers() {
+        parse_test_ok(
+            parse_ion_version_marker,
+            "$ion_1_0 ",
+            TextStreamItem::VersionMarker(1, 0),
+        );
+        parse_test_ok(
+            parse_ion_version_marker,
+            "$ion_2_13\n",
+            TextStreamItem::VersionMarker(2, 13),
+        );
+        parse_test_err(parse_ion_version_marker, "$ion_1_0a ");
+        parse_test_err(parse_ion_version_marker, "$ion_1 ");
+        parse_test_err(parse_ion_version_marker, "'$ion_1_0' ");
+        parse_test_err(parse_ion_version_marker, "$ion_1_0::");
+    }
+
+    #[test]
+    fn test_parse_symbol_tokens() {
+        let parse_token = |text: &str| parse_symbol_token(text).unwrap().1;
+        assert_eq!(parse_token("foo:"), TextSymbolToken::Text("foo".to_owned()));
+        assert_eq!(
+            parse_token("'foo bar':"),
+            TextSymbolToken::Text("foo bar".to_owned())
+        );
+        assert_eq!(parse_token("$10:"), TextSymbolToken::SymbolId(10));
+        assert_eq!(
+            parse_token("'$10':"),
+            TextSymbolToken::Text("$10".to_owned())
+        );
+        assert_eq!(
+            parse_token("$10a:"),
+            TextSymbolToken::Text("$10a".to_owned())
+        );
+        assert_eq!(parse_token("$_:"), TextSymbolToken::Text("$_".to_owned()));
+
+        // Symbol IDs that don't fit in a usize are rejected
+        assert!(parse_symbol_token("$99999999999999999999999999:").is_err());
+        // Cannot be the last thing in input (stream might be incomplete)
+        assert!(parse_symbol_token("foo").is_err());
+    }
+
+    #[test]
+    fn test_parse_operators() {
+        let parse_equals =
+            |text: &str, expected: &str| parse_test_ok(parse_operator, text, symbol(expected));
+        parse_equals("+ ", "+");
+        parse_equals("<=)", "<=");
+        parse_equals("!==a", "!==");
+        parse_equals("...b", "...");
+        parse_test_err(parse_operator, "a+ ");
+    }
+
+    #[test]
+    fn test_parse_sexp_identifiers() {
+        let parse_equals = |text: &str, expected: &str| {
+            parse_test_ok(parse_sexp_identifier, text, symbol(expected))
+        };
+        parse_equals("a+b", "a");
+        parse_equals("foo<=", "foo");
+        parse_equals("foo)", "foo");
+        parse_equals("foo ", "foo");
+        parse_test_err(parse_sexp_identifier, "+a ");
+    }
 }
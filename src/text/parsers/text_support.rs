@@ -4,7 +4,7 @@ use crate::result::{decoding_error_raw, IonError};
 use nom::branch::alt;
 use nom::bytes::streaming::tag;
 use nom::character::streaming::{char, satisfy};
-use nom::combinator::{map, map_res, recognize, value};
+use nom::combinator::{map, map_opt, map_res, recognize, value, verify};
 use nom::sequence::{preceded, tuple};
 use nom::{AsChar, IResult};
 
@@ -60,8 +60,18 @@ pub(crate) fn escaped_char_literal(input: &str) -> IResult<&str, char> {
 }
 
 /// Matches a Unicode escape (starting with '\x', '\u', or '\U'), returning the appropriate
-/// substitute character.
+/// substitute character. A pair of '\u' escapes that encode a UTF-16 surrogate pair (as JSON
+/// requires for characters outside of the Basic Multilingual Plane) is combined into one character.
 pub(crate) fn escaped_char_unicode(input: &str) -> IResult<&str, char> {
+    alt((
+        escaped_char_unicode_surrogate_pair,
+        escaped_char_unicode_code_point,
+    ))(input)
+}
+
+/// Matches a Unicode escape (starting with '\x', '\u', or '\U') that encodes a single code
+/// point, returning the appropriate substitute character.
+pub(crate) fn escaped_char_unicode_code_point(input: &str) -> IResult<&str, char> {
     map_res::<_, _, _, _, IonError, _, _>(
         alt((
             escaped_char_unicode_2_digit_hex,
@@ -86,6 +96,32 @@ pub(crate) fn escaped_char_unicode(input: &str) -> IResult<&str, char> {
     )(input)
 }
 
+/// Matches a '\u' escape of a high surrogate followed by a '\u' escape of a low surrogate (for
+/// example, '\uD83D\uDE00'), returning the character that the pair encodes.
+pub(crate) fn escaped_char_unicode_surrogate_pair(input: &str) -> IResult<&str, char> {
+    map_opt(
+        tuple((
+            verify(escaped_char_unicode_4_digit_value, |high: &u32| {
+                (0xD800..=0xDBFF).contains(high)
+            }),
+            preceded(
+                char('\\'),
+                verify(escaped_char_unicode_4_digit_value, |low: &u32| {
+                    (0xDC00..=0xDFFF).contains(low)
+                }),
+            ),
+        )),
+        |(high, low)| std::char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)),
+    )(input)
+}
+
+/// Matches a 4-digit Unicode escape (starting with '\u'), returning the value of its hex digits.
+fn escaped_char_unicode_4_digit_value(input: &str) -> IResult<&str, u32> {
+    map_res(escaped_char_unicode_4_digit_hex, |hex_digits| {
+        u32::from_str_radix(hex_digits, 16)
+    })(input)
+}
+
 /// Matches a 2-digit Unicode escape (starting with '\x'), returning the appropriate
 /// substitute character.
 pub(crate) fn escaped_char_unicode_2_digit_hex(input: &str) -> IResult<&str, &str> {
@@ -1,17 +1,59 @@
+use crate::cursor::RawSymbolToken;
 use crate::result::{illegal_operation, IonResult};
+use crate::types::coefficient::Sign;
+use crate::types::decimal::Decimal;
+use crate::types::magnitude::Magnitude;
+use crate::types::timestamp::{Precision, Timestamp};
+use crate::types::SymbolId;
+use crate::writer::IonWriter;
 use crate::IonType;
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, FixedOffset};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Timelike};
+use num_bigint::BigInt;
+use std::io;
 use std::io::{BufWriter, Write};
 
 pub struct TextWriter<W: Write> {
     output: BufWriter<W>,
+    // The annotations and field name of the next value, formatted as they will be written.
     annotations: Vec<String>,
     field_name: Option<String>,
-    containers: Vec<IonType>,
+    containers: Vec<Container>,
+    // If set, values are written over multiple lines with indentation. Otherwise, the output is
+    // compact.
+    pretty: Option<PrettyOptions>,
+    // If true, values are down-converted to JSON as they are written.
+    json: bool,
     string_escape_codes: Vec<String>,
 }
 
+// A container that the TextWriter has stepped into.
+struct Container {
+    ion_type: IonType,
+    // Used in pretty and JSON modes to decide whether a delimiter is needed before the next value.
+    num_values: usize,
+}
+
+/// Controls the layout of the output of a pretty-printing [TextWriter]. When pretty printing,
+/// each value in a container (including each field of a struct) is written on its own line.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PrettyOptions {
+    /// The number of spaces used to indent each level of nesting.
+    pub indentation: usize,
+    /// The number of line breaks written after each top-level value. A value of `2` leaves a
+    /// blank line between top-level values.
+    pub top_level_line_breaks: usize,
+}
+
+impl Default for PrettyOptions {
+    fn default() -> Self {
+        PrettyOptions {
+            indentation: 2,
+            top_level_line_breaks: 1,
+        }
+    }
+}
+
 /**
  * String escape codes, for Ion Clob.
  */
@@ -38,6 +80,119 @@ fn string_escape_code_init() -> Vec<String> {
     return string_escape_codes;
 }
 
+// Writes the Ion text representation of the provided timestamp.
+fn write_timestamp_text<O: Write>(output: &mut O, value: &Timestamp) -> IonResult<()> {
+    // Timestamps store their date and time in UTC. The text encoding uses local time.
+    let offset_seconds = value.offset.map_or(0, |offset| offset.local_minus_utc());
+    let local = value.date_time + Duration::seconds(offset_seconds as i64);
+
+    write!(output, "{:04}", local.year())?;
+    if value.precision == Precision::Year {
+        write!(output, "T")?;
+        return Ok(());
+    }
+    write!(output, "-{:02}", local.month())?;
+    if value.precision == Precision::Month {
+        write!(output, "T")?;
+        return Ok(());
+    }
+    write!(output, "-{:02}", local.day())?;
+    if value.precision == Precision::Day {
+        return Ok(());
+    }
+    write!(output, "T{:02}:{:02}", local.hour(), local.minute())?;
+    if value.precision >= Precision::Second {
+        write!(output, ":{:02}", local.second())?;
+    }
+    if value.precision == Precision::FractionalSeconds {
+        match value.fractional_seconds_as_decimal() {
+            Some(decimal) if decimal.exponent < 0 => {
+                // The coefficient is zero-padded to the number of digits of precision.
+                let digits = magnitude_digits(decimal.coefficient.magnitude());
+                write!(output, ".{:0>1$}", digits, (-decimal.exponent) as usize)?;
+            }
+            _ => {}
+        }
+    }
+    match value.offset {
+        None => write!(output, "-00:00")?,
+        Some(_) if offset_seconds == 0 => write!(output, "Z")?,
+        Some(_) => {
+            let sign = if offset_seconds < 0 { '-' } else { '+' };
+            let offset_minutes = offset_seconds.abs() / 60;
+            write!(
+                output,
+                "{}{:02}:{:02}",
+                sign,
+                offset_minutes / 60,
+                offset_minutes % 60
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// Escapes the provided text so that it can be written as (part of) a JSON string.
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{08}' => escaped.push_str("\\b"),
+            '\u{0C}' => escaped.push_str("\\f"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Returns the base-10 digits of the provided magnitude.
+fn magnitude_digits(magnitude: &Magnitude) -> String {
+    match magnitude {
+        Magnitude::U64(value) => value.to_string(),
+        Magnitude::BigUInt(value) => value.to_string(),
+    }
+}
+
+// Returns true if the provided symbol text cannot be written without quotes. Unquoted symbols
+// must be identifiers, and must not be keywords or look like symbol IDs (`$10`) or Ion version
+// markers (`$ion_1_0`), which would change their meaning.
+fn symbol_needs_quotes(text: &str) -> bool {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {}
+        _ => return true, // Empty text or a leading character that can't start an identifier
+    }
+    if !chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$') {
+        return true;
+    }
+    if let "null" | "true" | "false" | "nan" = text {
+        return true;
+    }
+    if let Some(digits) = text.strip_prefix('$') {
+        if digits.chars().all(|c| c.is_ascii_digit()) {
+            return true;
+        }
+    }
+    if let Some(version) = text.strip_prefix("$ion_") {
+        let mut parts = version.split('_');
+        let is_number = |part: Option<&str>| {
+            part.map_or(false, |p| {
+                !p.is_empty() && p.chars().all(|c| c.is_ascii_digit())
+            })
+        };
+        if is_number(parts.next()) && is_number(parts.next()) && parts.next().is_none() {
+            return true;
+        }
+    }
+    false
+}
+
 impl<W: Write> TextWriter<W> {
     /// Constructs a new instance of TextWriter that writes values to the provided io::Write
     /// implementation.
@@ -47,10 +202,48 @@ impl<W: Write> TextWriter<W> {
             annotations: vec![],
             field_name: None,
             containers: vec![],
+            pretty: None,
+            json: false,
             string_escape_codes: string_escape_code_init(),
         }
     }
 
+    /// Constructs a new instance of TextWriter that writes JSON to the provided io::Write
+    /// implementation. Values are down-converted following the Ion-to-JSON rules:
+    /// * annotations are dropped
+    /// * symbols, timestamps, blobs (base64-encoded) and clobs are written as strings
+    /// * s-expressions are written as arrays
+    /// * nulls of any type, `nan`, `+inf` and `-inf` are written as `null`
+    pub fn json(sink: W) -> TextWriter<W> {
+        TextWriter {
+            json: true,
+            ..TextWriter::new(sink)
+        }
+    }
+
+    /// Like [TextWriter::json], but pretty prints the JSON using the default [PrettyOptions].
+    pub fn pretty_json(sink: W) -> TextWriter<W> {
+        TextWriter {
+            json: true,
+            ..TextWriter::pretty(sink)
+        }
+    }
+
+    /// Constructs a new instance of TextWriter that pretty prints values to the provided
+    /// io::Write implementation using the default [PrettyOptions].
+    pub fn pretty(sink: W) -> TextWriter<W> {
+        TextWriter::with_pretty_options(sink, PrettyOptions::default())
+    }
+
+    /// Constructs a new instance of TextWriter that pretty prints values to the provided
+    /// io::Write implementation using the specified [PrettyOptions].
+    pub fn with_pretty_options(sink: W, options: PrettyOptions) -> TextWriter<W> {
+        TextWriter {
+            pretty: Some(options),
+            ..TextWriter::new(sink)
+        }
+    }
+
     /// Returns a reference to the underlying io::Write implementation.
     pub fn output(&self) -> &W {
         self.output.get_ref()
@@ -63,6 +256,13 @@ impl<W: Write> TextWriter<W> {
         self.output.get_mut()
     }
 
+    /// Writes any buffered data to the underlying io::Write implementation, then consumes the
+    /// writer and returns it.
+    pub fn into_output(self) -> IonResult<W> {
+        let output = self.output.into_inner().map_err(io::Error::from)?;
+        Ok(output)
+    }
+
     /// Causes any buffered data to be written to the underlying io::Write implementation.
     pub fn flush(&mut self) -> IonResult<()> {
         self.output.flush()?;
@@ -73,13 +273,47 @@ impl<W: Write> TextWriter<W> {
     /// of a struct, the field name will be written before the next value. Otherwise, it will be
     /// ignored.
     pub fn set_field_name(&mut self, name: &str) {
-        self.field_name = Some(name.to_string());
+        let field_name = if self.json {
+            format!("\"{}\"", json_escape(name))
+        } else {
+            self.format_symbol(name)
+        };
+        self.field_name = Some(field_name);
     }
 
-    /// Sets a list of annotations that will be applied to the next value that is written.
+    /// Sets a list of annotations that will be applied to the next value that is written. When
+    /// writing JSON, annotations are ignored.
     pub fn set_annotations(&mut self, annotations: &[&str]) {
-        self.annotations
-            .extend(annotations.iter().map(|s| s.to_string()));
+        if self.json {
+            return;
+        }
+        for annotation in annotations {
+            let annotation = self.format_symbol(annotation);
+            self.annotations.push(annotation);
+        }
+    }
+
+    // Returns the symbol text as it should be written. Text that is a valid identifier is
+    // written as-is; anything else is wrapped in single quotes and escaped.
+    fn format_symbol(&self, text: &str) -> String {
+        if !symbol_needs_quotes(text) {
+            return text.to_string();
+        }
+        let mut quoted = String::with_capacity(text.len() + 2);
+        quoted.push('\'');
+        for c in text.chars() {
+            match c {
+                '\'' => quoted.push_str("\\'"),
+                // Double quotes do not need to be escaped inside of a quoted symbol.
+                '"' => quoted.push(c),
+                _ => match self.string_escape_codes.get(c as usize) {
+                    Some(escaped_char) if escaped_char != "" => quoted.push_str(escaped_char),
+                    _ => quoted.push(c),
+                },
+            }
+        }
+        quoted.push('\'');
+        quoted
     }
 
     /// Begins a container (List, S-Expression, or Struct). If `ion_type` is not a container type,
@@ -87,19 +321,31 @@ impl<W: Write> TextWriter<W> {
     pub fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
         use IonType::*;
         self.write_value_metadata()?;
+        // JSON has no s-expressions; they are written as arrays instead.
+        let ion_type = match ion_type {
+            SExpression if self.json => List,
+            other => other,
+        };
         match ion_type {
             Struct => write!(self.output, "{{")?,
             List => write!(self.output, "[")?,
             SExpression => write!(self.output, "(")?,
             _ => return illegal_operation(format!("Cannot step into a(n) {:?}", ion_type)),
         }
-        self.containers.push(ion_type);
+        self.containers.push(Container {
+            ion_type,
+            num_values: 0,
+        });
         Ok(())
     }
 
     /// Returns true if the TextWriter is currently positioned within a Struct.
     pub fn is_in_struct(&self) -> bool {
-        if let Some(IonType::Struct) = self.containers.last() {
+        if let Some(Container {
+            ion_type: IonType::Struct,
+            ..
+        }) = self.containers.last()
+        {
             return true;
         }
         false
@@ -109,13 +355,20 @@ impl<W: Write> TextWriter<W> {
     // container, `step_out` will return an Err(IllegalOperation).
     pub fn step_out(&mut self) -> IonResult<()> {
         use IonType::*;
-        let end_delimiter = match self.containers.pop() {
-            Some(Struct) => "}",
-            Some(List) => "]",
-            Some(SExpression) => ")",
-            Some(scalar) => unreachable!("Inside a non-container type: {:?}", scalar),
+        let container = match self.containers.pop() {
+            Some(container) => container,
             None => return illegal_operation("Cannot step out of the top level."),
         };
+        let end_delimiter = match container.ion_type {
+            Struct => "}",
+            List => "]",
+            SExpression => ")",
+            scalar => unreachable!("Inside a non-container type: {:?}", scalar),
+        };
+        // Pretty printed containers that have values end on their own line.
+        if self.pretty.is_some() && container.num_values > 0 {
+            self.write_indentation()?;
+        }
         write!(self.output, "{}", end_delimiter)?;
         self.write_value_delimiter()?;
         Ok(())
@@ -124,7 +377,18 @@ impl<W: Write> TextWriter<W> {
     // Called after each value is written to emit an appropriate delimiter before the next value.
     fn write_value_delimiter(&mut self) -> IonResult<()> {
         use IonType::*;
-        let delimiter = match self.containers.last() {
+        if self.pretty.is_some() || self.json {
+            // Values in containers are delimited before the next value is written (see
+            // `write_value_prefix`) so that no delimiter trails the last one.
+            if self.containers.is_empty() {
+                let line_breaks = self
+                    .pretty
+                    .map_or(1, |options| options.top_level_line_breaks);
+                write!(self.output, "{}", "\n".repeat(line_breaks))?;
+            }
+            return Ok(());
+        }
+        let delimiter = match self.containers.last().map(|c| c.ion_type) {
             Some(Struct) | Some(List) => ",",
             Some(SExpression) => " ",
             Some(scalar) => unreachable!("Inside a non-container type: {:?}", scalar),
@@ -134,16 +398,46 @@ impl<W: Write> TextWriter<W> {
         Ok(())
     }
 
+    // In pretty and JSON modes, called before each value in a container is written to emit the
+    // delimiter that follows the previous value (if any) and, if pretty printing, to start a new,
+    // indented line.
+    fn write_value_prefix(&mut self) -> IonResult<()> {
+        let container = match self.containers.last_mut() {
+            Some(container) => container,
+            None => return Ok(()),
+        };
+        let needs_comma = container.num_values > 0 && container.ion_type != IonType::SExpression;
+        container.num_values += 1;
+        if needs_comma {
+            write!(self.output, ",")?;
+        }
+        self.write_indentation()
+    }
+
+    // Starts a new line, indented to the current depth.
+    fn write_indentation(&mut self) -> IonResult<()> {
+        let indentation = match &self.pretty {
+            Some(options) => options.indentation * self.containers.len(),
+            None => return Ok(()),
+        };
+        write!(self.output, "\n{:1$}", "", indentation)?;
+        Ok(())
+    }
+
     // Write the field name and annotations if set
     fn write_value_metadata(&mut self) -> IonResult<()> {
+        if self.pretty.is_some() || self.json {
+            self.write_value_prefix()?;
+        }
         if let Some(field_name) = &self.field_name.take() {
-            write!(self.output, "{}:", field_name)?;
+            let separator = if self.pretty.is_some() { ": " } else { ":" };
+            write!(self.output, "{}{}", field_name, separator)?;
         } else if self.is_in_struct() {
             return illegal_operation(format!("Values inside a struct must have a field name."));
         }
         if !self.annotations.is_empty() {
             for annotation in &self.annotations {
-                write!(self.output, "'{}'::", annotation)?;
+                write!(self.output, "{}::", annotation)?;
             }
             self.annotations.clear();
         }
@@ -168,8 +462,10 @@ impl<W: Write> TextWriter<W> {
     /// Writes an Ion null of the specified type.
     pub fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
         use IonType::*;
+        let json = self.json;
         self.write_scalar(|output| {
             let null_text = match ion_type {
+                _ if json => "null",
                 Null => "null",
                 Boolean => "null.bool",
                 Integer => "null.int",
@@ -209,9 +505,29 @@ impl<W: Write> TextWriter<W> {
         })
     }
 
+    /// Writes the provided BigInt value as an Ion integer.
+    pub fn write_big_int(&mut self, value: &BigInt) -> IonResult<()> {
+        self.write_scalar(|output| {
+            write!(output, "{}", value)?;
+            Ok(())
+        })
+    }
+
+    /// Writes the provided f32 value as an Ion float.
+    pub fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        self.write_f64(value as f64)
+    }
+
     /// Writes the provided f64 value as an Ion float.
     pub fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        let json = self.json;
         self.write_scalar(|output| {
+            // JSON cannot represent these values.
+            if json && (value.is_nan() || value.is_infinite()) {
+                write!(output, "null")?;
+                return Ok(());
+            }
+
             if value.is_nan() {
                 write!(output, "nan")?;
                 return Ok(());
@@ -249,31 +565,120 @@ impl<W: Write> TextWriter<W> {
 
     /// Writes the provided DateTime value as an Ion timestamp.
     pub fn write_datetime(&mut self, value: &DateTime<FixedOffset>) -> IonResult<()> {
+        let json = self.json;
+        self.write_scalar(|output| {
+            if json {
+                write!(output, "\"{}\"", value.to_rfc3339())?;
+            } else {
+                write!(output, "{}", value.to_rfc3339())?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Writes the provided Decimal value as an Ion decimal. Unlike [TextWriter::write_big_decimal],
+    /// this preserves negative zero and the decimal's exact precision.
+    pub fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        let json = self.json;
         self.write_scalar(|output| {
-            write!(output, "{}", value.to_rfc3339())?;
+            let sign = match value.coefficient.sign() {
+                Sign::Negative => "-",
+                Sign::Positive => "",
+            };
+            let digits = magnitude_digits(value.coefficient.magnitude());
+            let exponent = value.exponent;
+            if exponent == 0 && json {
+                // JSON numbers cannot end with a decimal point.
+                write!(output, "{}{}", sign, digits)?;
+            } else if exponent == 0 {
+                // e.g. `16.`
+                write!(output, "{}{}.", sign, digits)?;
+            } else if exponent < 0 && exponent >= -(digits.len() as i64) {
+                // The decimal point falls within the digits, e.g. `1.6` or `0.16`
+                let (whole, fraction) = digits.split_at(digits.len() - (-exponent) as usize);
+                let whole = if whole.is_empty() { "0" } else { whole };
+                write!(output, "{}{}.{}", sign, whole, fraction)?;
+            } else {
+                // e.g. `16d3` or `16d-5`; JSON uses `e` to introduce the exponent.
+                let exponent_marker = if json { "e" } else { "d" };
+                write!(output, "{}{}{}{}", sign, digits, exponent_marker, exponent)?;
+            }
             Ok(())
         })
     }
 
+    /// Writes the provided Timestamp value as an Ion timestamp. Unlike
+    /// [TextWriter::write_datetime], this preserves the timestamp's precision and unknown
+    /// offsets.
+    pub fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        let json = self.json;
+        self.write_scalar(|output| {
+            if json {
+                write!(output, "\"")?;
+                write_timestamp_text(output, value)?;
+                write!(output, "\"")?;
+                return Ok(());
+            }
+            write_timestamp_text(output, value)
+        })
+    }
+
     /// Writes the provided &str value as an Ion symbol.
     pub fn write_symbol<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        if self.json {
+            return self.write_string(value);
+        }
+        let symbol_text = self.format_symbol(value.as_ref());
         self.write_scalar(|output| {
-            write!(output, "'{}'", value.as_ref())?;
+            write!(output, "{}", symbol_text)?;
+            Ok(())
+        })
+    }
+
+    /// Writes a symbol with the provided symbol ID and unknown text (e.g. `$10`).
+    pub fn write_symbol_id(&mut self, symbol_id: SymbolId) -> IonResult<()> {
+        let json = self.json;
+        self.write_scalar(|output| {
+            if json {
+                write!(output, "\"${}\"", symbol_id)?;
+                return Ok(());
+            }
+            write!(output, "${}", symbol_id)?;
             Ok(())
         })
     }
 
     /// Writes the provided &str value as an Ion string.
     pub fn write_string<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        let text = value.as_ref();
+        if self.json {
+            let string_value = json_escape(text);
+            return self.write_scalar(|output| {
+                write!(output, "\"{}\"", string_value)?;
+                Ok(())
+            });
+        }
+        let mut string_value = String::with_capacity(text.len());
+        for c in text.chars() {
+            match self.string_escape_codes.get(c as usize) {
+                Some(escaped_char) if escaped_char != "" => string_value.push_str(escaped_char),
+                _ => string_value.push(c),
+            }
+        }
         self.write_scalar(|output| {
-            write!(output, "\"{}\"", value.as_ref())?;
+            write!(output, "\"{}\"", string_value)?;
             Ok(())
         })
     }
 
     /// Writes the provided byte array slice as an Ion blob.
     pub fn write_blob(&mut self, value: &[u8]) -> IonResult<()> {
+        let json = self.json;
         self.write_scalar(|output| {
+            if json {
+                write!(output, "\"{}\"", base64::encode(value))?;
+                return Ok(());
+            }
             // Rust format strings escape curly braces by doubling them. The following string is:
             // * The opening {{ from a text Ion blob, with each brace doubled to escape it.
             // * A {} pair used by the format string to indicate where the base64-encoded bytes
@@ -287,6 +692,11 @@ impl<W: Write> TextWriter<W> {
 
     /// Writes the provided byte array slice as an Ion clob.
     pub fn write_clob(&mut self, value: &[u8]) -> IonResult<()> {
+        if self.json {
+            // Each byte of the clob becomes the code point with the same value.
+            let text: String = value.iter().map(|byte| *byte as char).collect();
+            return self.write_string(text);
+        }
         // clob_value to be written based on defined STRING_ESCAPE_CODES.
         const NUM_DELIMITER_BYTES: usize = 4; // {{}}
         const NUM_HEX_BYTES_PER_BYTE: usize = 4; // \xHH
@@ -311,13 +721,114 @@ impl<W: Write> TextWriter<W> {
     }
 }
 
+impl<W: Write> IonWriter for TextWriter<W> {
+    fn set_annotations(&mut self, annotations: &[RawSymbolToken]) -> IonResult<()> {
+        if self.json {
+            return Ok(());
+        }
+        for annotation in annotations {
+            let annotation = match annotation {
+                RawSymbolToken::SymbolId(sid) => format!("${}", sid),
+                RawSymbolToken::Text(text) => self.format_symbol(text),
+            };
+            self.annotations.push(annotation);
+        }
+        Ok(())
+    }
+
+    fn set_field_name(&mut self, name: RawSymbolToken) -> IonResult<()> {
+        match name {
+            RawSymbolToken::SymbolId(sid) if self.json => {
+                self.field_name = Some(format!("\"${}\"", sid))
+            }
+            RawSymbolToken::SymbolId(sid) => self.field_name = Some(format!("${}", sid)),
+            RawSymbolToken::Text(text) => TextWriter::set_field_name(self, text),
+        }
+        Ok(())
+    }
+
+    fn write_symbol(&mut self, value: RawSymbolToken) -> IonResult<()> {
+        match value {
+            RawSymbolToken::SymbolId(sid) => self.write_symbol_id(sid),
+            RawSymbolToken::Text(text) => TextWriter::write_symbol(self, text),
+        }
+    }
+
+    fn write_string(&mut self, value: &str) -> IonResult<()> {
+        TextWriter::write_string(self, value)
+    }
+
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        TextWriter::write_null(self, ion_type)
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        TextWriter::write_bool(self, value)
+    }
+
+    fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        TextWriter::write_i64(self, value)
+    }
+
+    fn write_big_int(&mut self, value: &BigInt) -> IonResult<()> {
+        TextWriter::write_big_int(self, value)
+    }
+
+    fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        TextWriter::write_f32(self, value)
+    }
+
+    fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        TextWriter::write_f64(self, value)
+    }
+
+    fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()> {
+        TextWriter::write_big_decimal(self, value)
+    }
+
+    fn write_datetime(&mut self, value: &DateTime<FixedOffset>) -> IonResult<()> {
+        TextWriter::write_datetime(self, value)
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        TextWriter::write_decimal(self, value)
+    }
+
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        TextWriter::write_timestamp(self, value)
+    }
+
+    fn write_clob(&mut self, value: &[u8]) -> IonResult<()> {
+        TextWriter::write_clob(self, value)
+    }
+
+    fn write_blob(&mut self, value: &[u8]) -> IonResult<()> {
+        TextWriter::write_blob(self, value)
+    }
+
+    fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
+        TextWriter::step_in(self, ion_type)
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        TextWriter::step_out(self)
+    }
+
+    fn flush(&mut self) -> IonResult<()> {
+        TextWriter::flush(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::result::IonResult;
-    use crate::text::writer::TextWriter;
+    use crate::text::writer::{PrettyOptions, TextWriter};
+    use crate::types::decimal::Decimal;
+    use crate::types::timestamp::Timestamp;
     use crate::IonType;
     use bigdecimal::BigDecimal;
     use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use num_bigint::BigInt;
     use std::str;
     use std::str::FromStr;
 
@@ -332,6 +843,28 @@ mod tests {
         assert_eq!(str::from_utf8(&output).unwrap(), expected);
     }
 
+    fn json_writer_test<F>(mut commands: F, expected: &str)
+    where
+        F: FnMut(&mut TextWriter<&mut Vec<u8>>) -> IonResult<()>,
+    {
+        let mut output = Vec::new();
+        let mut writer = TextWriter::json(&mut output);
+        commands(&mut writer).expect("Invalid TextWriter test commands.");
+        drop(writer);
+        assert_eq!(str::from_utf8(&output).unwrap(), expected);
+    }
+
+    fn pretty_writer_test<F>(options: PrettyOptions, mut commands: F, expected: &str)
+    where
+        F: FnMut(&mut TextWriter<&mut Vec<u8>>) -> IonResult<()>,
+    {
+        let mut output = Vec::new();
+        let mut writer = TextWriter::with_pretty_options(&mut output, options);
+        commands(&mut writer).expect("Invalid TextWriter test commands.");
+        drop(writer);
+        assert_eq!(str::from_utf8(&output).unwrap(), expected);
+    }
+
     #[test]
     fn write_null_null() {
         writer_test(|w| w.write_null(IonType::Null), "null\n");
@@ -357,11 +890,30 @@ mod tests {
         writer_test(|w| w.write_i64(7), "7\n");
     }
 
+    #[test]
+    fn write_big_int() {
+        writer_test(
+            |w| {
+                w.write_big_int(&BigInt::from(u128::MAX))?;
+                w.write_big_int(&-BigInt::from(u128::MAX))
+            },
+            "340282366920938463463374607431768211455\n-340282366920938463463374607431768211455\n",
+        );
+    }
+
     #[test]
     fn write_f64() {
         writer_test(|w| w.write_f64(700f64), "7e2\n");
     }
 
+    #[test]
+    fn write_string() {
+        writer_test(
+            |w| w.write_string("foo \"bar\"\n\u{1}é"),
+            "\"foo \\\"bar\\\"\\n\\x01\\xe9\"\n",
+        );
+    }
+
     #[test]
     fn write_annotated_i64() {
         writer_test(
@@ -369,7 +921,7 @@ mod tests {
                 w.set_annotations(&["foo", "bar", "baz"]);
                 w.write_i64(7)
             },
-            "'foo'::'bar'::'baz'::7\n",
+            "foo::bar::baz::7\n",
         );
     }
 
@@ -382,6 +934,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_ion_decimals() {
+        writer_test(
+            |w| {
+                w.write_decimal(&Decimal::new(16, 0))?;
+                w.write_decimal(&Decimal::new(-16, -1))?;
+                w.write_decimal(&Decimal::new(16, -2))?;
+                w.write_decimal(&Decimal::new(16, -5))?;
+                w.write_decimal(&Decimal::new(16, 3))?;
+                w.write_decimal(&Decimal::negative_zero())?;
+                w.write_decimal(&Decimal::negative_zero_with_exponent(-1))?;
+                w.write_decimal(&Decimal::new(0, -3))
+            },
+            "16.\n-1.6\n0.16\n16d-5\n16d3\n-0.\n-0.0\n0d-3\n",
+        );
+    }
+
+    #[test]
+    fn write_ion_timestamps() -> IonResult<()> {
+        let timestamps = [
+            Timestamp::with_year(2021).build()?,
+            Timestamp::with_year(2021).with_month(3).build()?,
+            Timestamp::with_ymd(2021, 3, 4).build()?,
+            Timestamp::with_ymd(2021, 3, 4)
+                .with_hour_and_minute(5, 6)
+                .build_at_unknown_offset()?,
+            Timestamp::with_ymd(2021, 3, 4)
+                .with_hms(5, 6, 7)
+                .build_at_offset(0)?,
+            Timestamp::with_ymd(2021, 3, 4)
+                .with_hms(23, 6, 7)
+                .with_milliseconds(80)
+                .build_at_offset(-330)?,
+            Timestamp::with_ymd(2021, 3, 4)
+                .with_hms(5, 6, 7)
+                .with_fractional_seconds(Decimal::new(123u64, -12))
+                .build_at_offset(60)?,
+            Timestamp::with_ymd(2021, 3, 4)
+                .with_hms(5, 6, 7)
+                .with_nanoseconds_and_precision(123_456_789, 12)
+                .build_at_offset(0)?,
+        ];
+        writer_test(
+            |w| {
+                for timestamp in &timestamps {
+                    w.write_timestamp(timestamp)?;
+                }
+                Ok(())
+            },
+            "2021T\n2021-03T\n2021-03-04\n2021-03-04T05:06-00:00\n2021-03-04T05:06:07Z\n\
+             2021-03-04T23:06:07.080-05:30\n2021-03-04T05:06:07.000000000123+01:00\n\
+             2021-03-04T05:06:07.123456789000Z\n",
+        );
+        Ok(())
+    }
+
     #[test]
     fn write_datetime_epoch() {
         let naive_datetime = NaiveDate::from_ymd(2000 as i32, 1 as u32, 1 as u32)
@@ -402,7 +1010,41 @@ mod tests {
                 w.write_i64(21)?;
                 w.write_symbol("bar")
             },
-            "\"foo\"\n21\n'bar'\n",
+            "\"foo\"\n21\nbar\n",
+        );
+    }
+
+    #[test]
+    fn write_quoted_symbols() {
+        writer_test(
+            |w| {
+                w.write_symbol("foo_Bar$1")?;
+                w.write_symbol("")?;
+                w.write_symbol("foo bar")?;
+                w.write_symbol("it's \"quoted\"\n")?;
+                w.write_symbol("null")?;
+                w.write_symbol("true")?;
+                w.write_symbol("1st")?;
+                w.write_symbol("$12")?;
+                w.write_symbol("$ion_1_0")?;
+                w.write_symbol("$ion_symbol_table")
+            },
+            "foo_Bar$1\n''\n'foo bar'\n'it\\'s \"quoted\"\\n'\n'null'\n'true'\n'1st'\n'$12'\n\
+             '$ion_1_0'\n$ion_symbol_table\n",
+        );
+    }
+
+    #[test]
+    fn write_quoted_field_names_and_annotations() {
+        writer_test(
+            |w| {
+                w.step_in(IonType::Struct)?;
+                w.set_field_name("a b");
+                w.set_annotations(&["false", "$10", "ok"]);
+                w.write_i64(1)?;
+                w.step_out()
+            },
+            "{'a b':'false'::'$10'::ok::1,}\n",
         );
     }
 
@@ -437,7 +1079,7 @@ mod tests {
                 w.write_symbol("bar")?;
                 w.step_out()
             },
-            "[\"foo\",21,'bar',]\n",
+            "[\"foo\",21,bar,]\n",
         );
     }
 
@@ -453,7 +1095,7 @@ mod tests {
                 w.step_out()?;
                 w.step_out()
             },
-            "[\"foo\",21,['bar',],]\n",
+            "[\"foo\",21,[bar,],]\n",
         );
     }
 
@@ -467,7 +1109,7 @@ mod tests {
                 w.write_symbol("bar")?;
                 w.step_out()
             },
-            "(\"foo\" 21 'bar' )\n",
+            "(\"foo\" 21 bar )\n",
         );
     }
 
@@ -485,7 +1127,133 @@ mod tests {
                 w.write_symbol("bar")?;
                 w.step_out()
             },
-            "{a:\"foo\",b:21,c:'qux'::'bar',}\n",
+            "{a:\"foo\",b:21,c:qux::bar,}\n",
+        );
+    }
+
+    #[test]
+    fn write_pretty_containers() {
+        pretty_writer_test(
+            PrettyOptions::default(),
+            |w| {
+                w.set_annotations(&["foo"]);
+                w.step_in(IonType::Struct)?;
+                w.set_field_name("a");
+                w.write_string("foo")?;
+                w.set_field_name("b");
+                w.step_in(IonType::List)?;
+                w.write_i64(1)?;
+                w.step_in(IonType::SExpression)?;
+                w.write_symbol("bar")?;
+                w.write_symbol("baz")?;
+                w.step_out()?;
+                w.step_out()?;
+                w.set_field_name("c");
+                w.step_in(IonType::Struct)?;
+                w.step_out()?;
+                w.step_out()
+            },
+            "\
+foo::{
+  a: \"foo\",
+  b: [
+    1,
+    (
+      bar
+      baz
+    )
+  ],
+  c: {}
+}
+",
         );
     }
+
+    #[test]
+    fn write_pretty_stream() {
+        pretty_writer_test(
+            PrettyOptions {
+                indentation: 4,
+                top_level_line_breaks: 2,
+            },
+            |w| {
+                w.write_i64(1)?;
+                w.step_in(IonType::List)?;
+                w.write_i64(2)?;
+                w.write_i64(3)?;
+                w.step_out()
+            },
+            "1\n\n[\n    2,\n    3\n]\n\n",
+        );
+    }
+
+    #[test]
+    fn write_json_scalars() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, 3, 4)
+            .with_hour_and_minute(5, 6)
+            .build_at_offset(0)?;
+        json_writer_test(
+            |w| {
+                w.write_null(IonType::Integer)?;
+                w.write_f64(f64::NAN)?;
+                w.write_f64(f64::NEG_INFINITY)?;
+                w.write_f64(1.5)?;
+                w.write_decimal(&Decimal::new(16, 0))?;
+                w.write_decimal(&Decimal::new(-16, -1))?;
+                w.write_decimal(&Decimal::new(16, 3))?;
+                w.write_timestamp(&timestamp)?;
+                w.write_symbol("null")?;
+                w.write_symbol_id(10)?;
+                w.write_string("a \"b\"\n\u{1}é")?;
+                w.write_blob("hello".as_bytes())?;
+                w.write_clob(&[b'a', b'"', 0x7F])
+            },
+            "null\nnull\nnull\n1.5e0\n16\n-1.6\n16e3\n\"2021-03-04T05:06Z\"\n\"null\"\n\"$10\"\n\
+             \"a \\\"b\\\"\\n\\u0001é\"\n\"aGVsbG8=\"\n\"a\\\"\u{7F}\"\n",
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_json_containers() {
+        json_writer_test(
+            |w| {
+                w.set_annotations(&["foo"]);
+                w.step_in(IonType::Struct)?;
+                w.set_field_name("a b");
+                w.step_in(IonType::SExpression)?;
+                w.write_symbol("c")?;
+                w.write_i64(1)?;
+                w.step_out()?;
+                w.set_field_name("d");
+                w.set_annotations(&["bar"]);
+                w.step_in(IonType::List)?;
+                w.step_out()?;
+                w.step_out()?;
+                w.step_in(IonType::List)?;
+                w.write_bool(true)?;
+                w.step_out()
+            },
+            "{\"a b\":[\"c\",1],\"d\":[]}\n[true]\n",
+        );
+    }
+
+    #[test]
+    fn write_pretty_json() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = TextWriter::pretty_json(&mut output);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("a");
+        writer.step_in(IonType::SExpression)?;
+        writer.write_i64(1)?;
+        writer.write_i64(2)?;
+        writer.step_out()?;
+        writer.step_out()?;
+        drop(writer);
+        assert_eq!(
+            "{\n  \"a\": [\n    1,\n    2\n  ]\n}\n",
+            str::from_utf8(&output).unwrap()
+        );
+        Ok(())
+    }
 }
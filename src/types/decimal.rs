@@ -3,6 +3,8 @@ use std::cmp::Ordering;
 use bigdecimal::{BigDecimal, Signed};
 use num_bigint::{BigInt, BigUint, ToBigUint};
 
+use crate::ion_eq::IonEq;
+use crate::ion_ord::IonOrd;
 use crate::result::IonError;
 use crate::types::coefficient::{Coefficient, Sign};
 use crate::types::magnitude::Magnitude;
@@ -44,9 +46,28 @@ impl Decimal {
         }
     }
 
+    /// Returns `true` if this Decimal's coefficient is zero, regardless of its sign or exponent.
+    fn is_zero(&self) -> bool {
+        *self.coefficient.magnitude() == Magnitude::U64(0)
+    }
+
     // Used in the implementation of Ord, which compares the sign of each decimal before
     // comparing the combined value of their exponents/magnitudes.
     fn compare(d1: &Decimal, d2: &Decimal) -> Ordering {
+        let value_cmp = Self::compare_values(d1, d2);
+        if value_cmp == Ordering::Equal && d1.is_zero() && d2.is_zero() {
+            // Ion only considers zeros with the same sign to be equal if their exponents are
+            // equal. If the exponents are different, we still need to decide between
+            // Ordering::Greater and Ordering::Less. We can order the zeros by comparing their
+            // exponents.
+            return d1.exponent.cmp(&d2.exponent);
+        }
+        value_cmp
+    }
+
+    // Compares the values of two Decimals without regard for their precision; `1.0` and `1.00`
+    // are considered equal, as are `0d0` and `0d5`. `-0` is considered less than `0`.
+    pub(crate) fn compare_values(d1: &Decimal, d2: &Decimal) -> Ordering {
         // Even if the exponents are wildly different, disagreement in the coefficient's signs
         // still tells which value is bigger. This approach causes `-0` to be considered less than
         // `0`, which may seem a bit quirky. However, according to the Ion data model, `-0` is not
@@ -56,47 +77,41 @@ impl Decimal {
             return sign_cmp;
         }
 
-        // If the exponents match, we can compare the two coefficients directly.
-        if d1.exponent == d2.exponent {
-            // We've already confirmed that the coefficients' signs match, so we can skip to
-            // comparing their magnitudes.
-            return d1.coefficient.magnitude().cmp(&d2.coefficient.magnitude());
-        }
-
-        // If the exponents don't match, we need to scale one of the magnitudes to match the other
-        // for comparison. For example, when comparing 16e3 and 1600e1, we can't compare the
-        // magnitudes (16 and 1600) directly. Instead, we need to multiply 16 by 10^2 to compensate
-        // for the difference in their exponents (3-1). Then we'll be comparing 1600 to 1600,
-        // and can safely conclude that they are equal.
-
-        // But first, a detour: Decimal zeros are a special case because we can't scale them via
-        // multiplication.
-        if *d1.coefficient.magnitude() == Magnitude::U64(0)
-            && *d2.coefficient.magnitude() == Magnitude::U64(0)
-        {
-            // Ion only considers zeros with the same sign to be equal if their exponents are
-            // equal. We already know the exponents are different, but we still need to decide
-            // between Ordering::Greater and Ordering::Less. We can order the zeros by comparing
-            // their exponents.
-            return d1.exponent.cmp(&d2.exponent);
-        }
-
-        // Scale up the magnitude associated with a lesser exponent and compare it with the
-        // other magnitude.
-        if d1.exponent < d2.exponent {
+        let magnitude_cmp = if d1.exponent == d2.exponent {
+            // If the exponents match, we can compare the two magnitudes directly.
+            d1.coefficient.magnitude().cmp(&d2.coefficient.magnitude())
+        } else if d1.is_zero() || d2.is_zero() {
+            // Decimal zeros are a special case because we can't scale them via multiplication.
+            // A zero is smaller than any non-zero magnitude.
+            d2.is_zero().cmp(&d1.is_zero())
+        } else if d1.exponent < d2.exponent {
+            // If the exponents don't match, we need to scale one of the magnitudes to match the
+            // other for comparison. For example, when comparing 16e3 and 1600e1, we can't compare
+            // the magnitudes (16 and 1600) directly. Instead, we need to multiply 16 by 10^2 to
+            // compensate for the difference in their exponents (3-1). Then we'll be comparing
+            // 1600 to 1600, and can safely conclude that they are equal.
             Self::compare_scaled_magnitudes(d1, d2)
         } else {
-            Self::compare_scaled_magnitudes(d2, d1)
+            Self::compare_scaled_magnitudes(d2, d1).reverse()
+        };
+
+        // We've already confirmed that the coefficients' signs match. If they're negative, the
+        // decimal with the larger magnitude is the smaller value.
+        match d1.coefficient.sign() {
+            Sign::Negative => magnitude_cmp.reverse(),
+            Sign::Positive => magnitude_cmp,
         }
     }
 
-    // d1 must have a smaller exponent than d2
+    // d1 must have a smaller exponent than d2. Scales up the magnitude of d2 to match the
+    // exponent of d1 and compares d1's magnitude with the result.
     fn compare_scaled_magnitudes(d1: &Decimal, d2: &Decimal) -> Ordering {
         let exponent_delta = d2.exponent - d1.exponent;
         let mut adjusted_magnitude: BigUint = d2.coefficient.magnitude().to_biguint().unwrap();
-        adjusted_magnitude *= 10u64.pow(exponent_delta as u32);
-        let cmp = Magnitude::BigUInt(adjusted_magnitude).cmp(&d1.coefficient.magnitude());
-        cmp
+        adjusted_magnitude *= num_traits::pow(BigUint::from(10u64), exponent_delta as usize);
+        d1.coefficient
+            .magnitude()
+            .cmp(&Magnitude::BigUInt(adjusted_magnitude))
     }
 }
 
@@ -108,6 +123,23 @@ impl PartialEq for Decimal {
 
 impl Eq for Decimal {}
 
+impl IonEq for Decimal {
+    /// Unlike [PartialEq], which compares the numeric values of two Decimals, this requires both
+    /// the coefficient (including its sign) and the exponent to match. `1.0` is not equivalent to
+    /// `1.00` and `-0.` is not equivalent to `0.`.
+    fn ion_eq(&self, other: &Self) -> bool {
+        self.exponent == other.exponent && self.coefficient == other.coefficient
+    }
+}
+
+impl IonOrd for Decimal {
+    /// Decimals are ordered by value. Decimals with the same value are ordered by precision, with
+    /// less precise values first: `-0.` < `0.` < `0.0` < `1.0` < `1.00` < `1.1`.
+    fn ion_cmp(&self, other: &Self) -> Ordering {
+        Decimal::compare_values(self, other).then_with(|| other.exponent.cmp(&self.exponent))
+    }
+}
+
 impl PartialOrd for Decimal {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(&other))
@@ -215,7 +247,41 @@ mod decimal_tests {
         assert!(Decimal::new(80, 3) > Decimal::new(-80, 3));
         assert!(Decimal::new(80, 3) > Decimal::new(8, 3));
         assert!(Decimal::new(80, 4) > Decimal::new(80, 3));
-        assert!(Decimal::new(1240, -3) > Decimal::new(124, -3))
+        assert!(Decimal::new(1240, -3) > Decimal::new(124, -3));
+        assert!(Decimal::new(2, 1) > Decimal::new(1, 0));
+        assert!(Decimal::new(1, 0) < Decimal::new(2, 1));
+        assert!(Decimal::new(-2, 0) < Decimal::new(-1, 0));
+        assert!(Decimal::new(-1, 1) < Decimal::new(-5, 0));
+        assert!(Decimal::new(-1, 0) < Decimal::negative_zero());
+        assert!(Decimal::negative_zero() < Decimal::new(0, 0));
+        assert!(Decimal::new(0, 0) < Decimal::new(1, -30));
+        assert!(Decimal::new(1, 30) > Decimal::new(u64::MAX, 0));
+    }
+
+    #[test]
+    fn test_decimal_ion_cmp() {
+        use crate::ion_ord::IonOrd;
+        let ordered = vec![
+            Decimal::new(-1, 0),
+            Decimal::negative_zero(),
+            Decimal::negative_zero_with_exponent(-1),
+            Decimal::new(0, 0),
+            Decimal::new(0, -1),
+            Decimal::new(10, -1),
+            Decimal::new(100, -2),
+            Decimal::new(11, -1),
+        ];
+        for (index, d1) in ordered.iter().enumerate() {
+            for (other_index, d2) in ordered.iter().enumerate() {
+                assert_eq!(
+                    index.cmp(&other_index),
+                    d1.ion_cmp(d2),
+                    "{:?} vs {:?}",
+                    d1,
+                    d2
+                );
+            }
+        }
     }
 
     #[test]
@@ -1,12 +1,16 @@
+use crate::ion_eq::IonEq;
+use crate::ion_ord::IonOrd;
 use crate::result::{illegal_operation, illegal_operation_raw, IonError, IonResult};
 use crate::types::decimal::Decimal;
 use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
 use ion_c_sys::timestamp::{IonDateTime, TSOffsetKind, TSPrecision};
+use num_bigint::BigUint;
+use std::cmp::Ordering;
 use std::convert::TryInto;
 use std::fmt::Debug;
 
 /// Indicates the most precise time unit that has been specified in the accompanying [Timestamp].
-#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
 pub enum Precision {
     /// Year-level precision (e.g. `2020T`)
     Year,
@@ -199,8 +203,11 @@ impl Timestamp {
     }
 }
 
-impl PartialEq for Timestamp {
-    fn eq(&self, other: &Self) -> bool {
+impl Timestamp {
+    /// Tests every field of two timestamps that is within their precision (except for their
+    /// fractional seconds) for equality. Timestamps with different precisions or offsets
+    /// (including "unknown") are never considered equal.
+    fn fields_equal_ignoring_fractional_seconds(&self, other: &Timestamp) -> bool {
         // Timestamps are only considered equal if they have the same precision.
         if self.precision != other.precision {
             return false;
@@ -228,6 +235,61 @@ impl PartialEq for Timestamp {
         if self.precision >= Precision::Second && self_dt.second() != other_dt.second() {
             return false;
         }
+        true
+    }
+
+    /// Returns the fractional seconds of this Timestamp as a Decimal whose exponent reflects
+    /// the number of digits of precision, or `None` if the Timestamp has no fractional seconds.
+    /// For example, a Timestamp with millisecond precision and a fractional seconds value of
+    /// `.120` would return a Decimal with a coefficient of `120` and an exponent of `-3`.
+    pub(crate) fn fractional_seconds_as_decimal(&self) -> Option<Decimal> {
+        let mantissa = self.fractional_seconds.as_ref()?;
+        let decimal = match mantissa {
+            Mantissa::Digits(digits) => {
+                let nanoseconds = self.date_time.nanosecond();
+                let exponent = -(*digits as i64);
+                if *digits <= 9 {
+                    // Discard any nanoseconds that are beyond the specified precision.
+                    Decimal::new(nanoseconds / 10u32.pow(9 - digits), exponent)
+                } else {
+                    let coefficient = BigUint::from(nanoseconds)
+                        * num_traits::pow(BigUint::from(10u32), (digits - 9) as usize);
+                    Decimal::new(coefficient, exponent)
+                }
+            }
+            Mantissa::Arbitrary(decimal) => decimal.clone(),
+        };
+        Some(decimal)
+    }
+
+    /// Returns the year, month, day, hour, minute and second of this Timestamp in UTC. Fields
+    /// that are beyond the Timestamp's precision are given their smallest possible value.
+    fn fields_within_precision(&self) -> (i32, u32, u32, u32, u32, u32) {
+        let date_time = self.date_time;
+        let precision = self.precision;
+        let field = |min_precision: Precision, value: u32, default: u32| {
+            if precision >= min_precision {
+                value
+            } else {
+                default
+            }
+        };
+        (
+            date_time.year(),
+            field(Precision::Month, date_time.month(), 1),
+            field(Precision::Day, date_time.day(), 1),
+            field(Precision::HourAndMinute, date_time.hour(), 0),
+            field(Precision::HourAndMinute, date_time.minute(), 0),
+            field(Precision::Second, date_time.second(), 0),
+        )
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        if !self.fields_equal_ignoring_fractional_seconds(other) {
+            return false;
+        }
         if self.precision >= Precision::FractionalSeconds && !self.fractional_seconds_equal(other) {
             return false;
         }
@@ -235,12 +297,76 @@ impl PartialEq for Timestamp {
     }
 }
 
-// We cannot provide an implementation of [Ord] for [Timestamp] because many instances cannot be
-// meaningfully compared to each other to derive an ordering. For example, a Timestamp with
-// Precision::Year cannot be compared to a timestamp with Precision::Day, and a Timestamp with
-// an unknown offset cannot be compared to one with a known offset.
-//TODO: It would be possible to provide a PartialOrd for Timestamps with the same precision and
-//      either known or unknown offsets. It's unclear how valuable that would be.
+impl IonEq for Timestamp {
+    /// Unlike [PartialEq], which compares the numeric value of each Timestamp's fractional seconds,
+    /// this requires the fractional seconds to have the same precision. `2021-01-01T00:00:00.1Z`
+    /// and `2021-01-01T00:00:00.10Z` are not equivalent.
+    fn ion_eq(&self, other: &Self) -> bool {
+        if !self.fields_equal_ignoring_fractional_seconds(other) {
+            return false;
+        }
+        if self.precision < Precision::FractionalSeconds {
+            return true;
+        }
+        match (
+            self.fractional_seconds_as_decimal(),
+            other.fractional_seconds_as_decimal(),
+        ) {
+            (Some(d1), Some(d2)) => d1.ion_eq(&d2),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl IonOrd for Timestamp {
+    /// Timestamps are ordered by the point in time that they represent. Timestamps that represent
+    /// the same point in time are ordered by precision (less precise first), then by offset
+    /// (unknown first, then from west to east), and finally by the number of digits in their
+    /// fractional seconds (fewer first). For example:
+    /// `2020T` < `2020-01-01T00:00-00:00` < `2020-01-01T00:00Z` < `2020-01-01T01:00+01:00` <
+    /// `2020-01-01T00:00:00.0Z` < `2020-01-01T00:00:00.00Z` < `2020-01-01T00:00:00.1Z`
+    fn ion_cmp(&self, other: &Self) -> Ordering {
+        let fields_cmp = self
+            .fields_within_precision()
+            .cmp(&other.fields_within_precision());
+        if fields_cmp != Ordering::Equal {
+            return fields_cmp;
+        }
+
+        let zero = Decimal::new(0, 0);
+        let self_fraction = self.fractional_seconds_as_decimal();
+        let other_fraction = other.fractional_seconds_as_decimal();
+        let fraction_cmp = Decimal::compare_values(
+            self_fraction.as_ref().unwrap_or(&zero),
+            other_fraction.as_ref().unwrap_or(&zero),
+        );
+        if fraction_cmp != Ordering::Equal {
+            return fraction_cmp;
+        }
+
+        self.precision
+            .cmp(&other.precision)
+            .then_with(|| {
+                let self_offset = self.offset.map(|offset| offset.local_minus_utc());
+                let other_offset = other.offset.map(|offset| offset.local_minus_utc());
+                self_offset.cmp(&other_offset)
+            })
+            .then_with(|| match (self_fraction, other_fraction) {
+                (Some(d1), Some(d2)) => d1.ion_cmp(&d2),
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Less,
+                (Some(_), None) => Ordering::Greater,
+            })
+    }
+}
+
+// We cannot provide an implementation of [Ord] for [Timestamp] that is consistent with its
+// implementation of [PartialEq], which considers the numeric value of fractional seconds rather
+// than their precision. Many instances also cannot be meaningfully compared to each other; for
+// example, a Timestamp with Precision::Year cannot be compared to a timestamp with Precision::Day
+// without choosing how to order values of differing precision. [IonOrd] makes those choices and
+// provides a total ordering over all Timestamps.
 
 /// A Builder object for incrementally configuring and finally instantiating a [Timestamp].
 /// For the time being, this type is not publicly visible. Users are expected to use any of the
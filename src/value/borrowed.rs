@@ -8,12 +8,15 @@
 //! backed by octets or string data, `&[u8]` and `&str` are used.
 
 use super::{Element, ImportSource, Sequence, Struct, SymbolToken};
+use crate::ion_eq::{element_ion_eq, IonEq};
+use crate::ion_ord::{element_ion_cmp, IonOrd};
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
 use crate::value::{AnyInt, Builder};
 use crate::IonType;
 use num_bigint::BigInt;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::iter::FromIterator;
 
@@ -429,6 +432,18 @@ impl<'val> PartialEq for BorrowedElement<'val> {
 
 impl<'val> Eq for BorrowedElement<'val> {}
 
+impl<'val> IonEq for BorrowedElement<'val> {
+    fn ion_eq(&self, other: &Self) -> bool {
+        element_ion_eq(self, other)
+    }
+}
+
+impl<'val> IonOrd for BorrowedElement<'val> {
+    fn ion_cmp(&self, other: &Self) -> Ordering {
+        element_ion_cmp(self, other)
+    }
+}
+
 impl<'val> From<BorrowedValue<'val>> for BorrowedElement<'val> {
     /// Constructs a [`BorrowedElement`] without annotations from this value.
     fn from(val: BorrowedValue<'val>) -> Self {
@@ -0,0 +1,435 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Provides a serde [`Deserializer`](de::Deserializer) over [`Element`] trees, mapping Ion types
+//! onto Rust the same way as [`crate::de`].
+
+use serde::de::value::{BorrowedStrDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeSeed, Visitor};
+use serde::{Deserialize, Deserializer as _};
+
+use crate::de::{visit_big_int, MapKeyDeserializer};
+use crate::result::{decoding_error, illegal_operation, IonError, IonResult};
+use crate::ser::{to_ion_text, DECIMAL_NEWTYPE_NAME, TIMESTAMP_NEWTYPE_NAME};
+use crate::value::native_reader::decimal_to_f64;
+use crate::value::owned::OwnedElement;
+use crate::value::{AnyInt, Element, Sequence, Struct, SymbolToken};
+use crate::IonType;
+
+/// Deserializes an instance of `T` from an [`OwnedElement`]. Strings, symbols and byte arrays
+/// can be borrowed from the element.
+///
+/// ```
+/// # use ion_rs::result::IonResult;
+/// # use ion_rs::value::from_element;
+/// # use ion_rs::value::native_reader::NativeElementReader;
+/// # use ion_rs::value::reader::ElementReader;
+/// # fn main() -> IonResult<()> {
+/// let element = NativeElementReader.read_one(b"[\"a\", b]")?;
+/// let letters: Vec<&str> = from_element(&element)?;
+/// assert_eq!(vec!["a", "b"], letters);
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_element<'de, T>(element: &'de OwnedElement) -> IonResult<T>
+where
+    T: Deserialize<'de>,
+{
+    T::deserialize(ElementDeserializer::new(element))
+}
+
+/// Deserializes Rust values from an [`Element`].
+struct ElementDeserializer<'de, E: Element> {
+    element: &'de E,
+    // The number of the element's annotations that have been read as enum variant names.
+    annotations_read: usize,
+}
+
+impl<'de, E: Element> ElementDeserializer<'de, E> {
+    fn new(element: &'de E) -> Self {
+        ElementDeserializer {
+            element,
+            annotations_read: 0,
+        }
+    }
+
+    // Returns the first of the element's annotations that has not been read yet.
+    fn next_annotation(&self) -> IonResult<Option<&'de str>> {
+        match self.element.annotations().nth(self.annotations_read) {
+            Some(annotation) => match annotation.text() {
+                Some(text) => Ok(Some(text)),
+                None => decoding_error("Cannot read an annotation with unknown text as an enum."),
+            },
+            None => Ok(None),
+        }
+    }
+
+    // Returns the text of the element if it is a symbol or string.
+    fn text(&self) -> IonResult<&'de str> {
+        match self.element.as_str() {
+            Some(text) => Ok(text),
+            None => decoding_error(format!(
+                "Cannot read a {:?} with unknown text as a string.",
+                self.element.ion_type()
+            )),
+        }
+    }
+}
+
+impl<'de, E: Element> de::Deserializer<'de> for ElementDeserializer<'de, E> {
+    type Error = IonError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        let element = self.element;
+        if element.is_null() {
+            return visitor.visit_unit();
+        }
+        match element.ion_type() {
+            IonType::Null => visitor.visit_unit(),
+            IonType::Boolean => visitor.visit_bool(try_to!(element.as_bool())),
+            IonType::Integer => match try_to!(element.as_any_int()) {
+                AnyInt::I64(value) => visitor.visit_i64(*value),
+                AnyInt::BigInt(value) => visit_big_int(value.clone(), visitor),
+            },
+            IonType::Float => visitor.visit_f64(try_to!(element.as_f64())),
+            IonType::Decimal => {
+                visitor.visit_f64(decimal_to_f64(try_to!(element.as_decimal()).clone())?)
+            }
+            IonType::Timestamp => {
+                let timestamp = try_to!(element.as_timestamp());
+                visitor.visit_string(to_ion_text(|writer| writer.write_timestamp(timestamp))?)
+            }
+            IonType::Symbol | IonType::String => visitor.visit_borrowed_str(self.text()?),
+            IonType::Clob | IonType::Blob => {
+                visitor.visit_borrowed_bytes(try_to!(element.as_bytes()))
+            }
+            IonType::List | IonType::SExpression => {
+                let children = try_to!(element.as_sequence()).iter();
+                visitor.visit_seq(ElementSeqAccess { children })
+            }
+            IonType::Struct => {
+                let fields = try_to!(element.as_struct()).iter();
+                visitor.visit_map(ElementMapAccess {
+                    fields,
+                    value: None,
+                })
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        if self.element.is_null() {
+            return visitor.visit_none();
+        }
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        match self.element.as_bytes() {
+            Some(bytes) => {
+                visitor.visit_seq(SeqDeserializer::<_, IonError>::new(bytes.iter().copied()))
+            }
+            None => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        match (name, self.element.as_decimal()) {
+            (DECIMAL_NEWTYPE_NAME, Some(decimal)) => {
+                visitor.visit_string(to_ion_text(|writer| writer.write_decimal(decimal))?)
+            }
+            (DECIMAL_NEWTYPE_NAME, None) | (TIMESTAMP_NEWTYPE_NAME, _) => {
+                self.deserialize_any(visitor)
+            }
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        if let Some(variant) = self.next_annotation()? {
+            return visitor.visit_enum(ElementEnum {
+                de: ElementDeserializer {
+                    element: self.element,
+                    annotations_read: self.annotations_read + 1,
+                },
+                variant,
+            });
+        }
+        match self.element.ion_type() {
+            IonType::Symbol | IonType::String if !self.element.is_null() => {
+                visitor.visit_enum(BorrowedStrDeserializer::<IonError>::new(self.text()?))
+            }
+            _ => decoding_error(format!(
+                "Expected a symbol or an annotated value for enum {}.",
+                name
+            )),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Deserializes the children of an Ion list or s-expression.
+struct ElementSeqAccess<'de, E: Element> {
+    children: Box<dyn Iterator<Item = &'de E> + 'de>,
+}
+
+impl<'de, E: Element> de::SeqAccess<'de> for ElementSeqAccess<'de, E> {
+    type Error = IonError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> IonResult<Option<T::Value>> {
+        match self.children.next() {
+            Some(child) => seed.deserialize(ElementDeserializer::new(child)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Deserializes the fields of an Ion struct.
+struct ElementMapAccess<'de, E: Element> {
+    fields: Box<dyn Iterator<Item = (&'de E::SymbolToken, &'de E)> + 'de>,
+    // The value of the field whose name was most recently deserialized.
+    value: Option<&'de E>,
+}
+
+impl<'de, E: Element> de::MapAccess<'de> for ElementMapAccess<'de, E> {
+    type Error = IonError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> IonResult<Option<K::Value>> {
+        let (name, value) = match self.fields.next() {
+            Some(field) => field,
+            None => return Ok(None),
+        };
+        let name = match name.text() {
+            Some(text) => text,
+            None => return decoding_error("Cannot read a struct field name with unknown text."),
+        };
+        self.value = Some(value);
+        seed.deserialize(MapKeyDeserializer::new(name)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> IonResult<V::Value> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(ElementDeserializer::new(value)),
+            None => decoding_error("next_value was called before next_key"),
+        }
+    }
+}
+
+/// Deserializes an enum variant whose name was read from one of the element's annotations.
+struct ElementEnum<'de, E: Element> {
+    de: ElementDeserializer<'de, E>,
+    variant: &'de str,
+}
+
+impl<'de, E: Element> de::EnumAccess<'de> for ElementEnum<'de, E> {
+    type Error = IonError;
+    type Variant = ElementDeserializer<'de, E>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> IonResult<(V::Value, ElementDeserializer<'de, E>)> {
+        let value = seed.deserialize(BorrowedStrDeserializer::<IonError>::new(self.variant))?;
+        Ok((value, self.de))
+    }
+}
+
+impl<'de, E: Element> de::VariantAccess<'de> for ElementDeserializer<'de, E> {
+    type Error = IonError;
+
+    fn unit_variant(self) -> IonResult<()> {
+        <()>::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> IonResult<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> IonResult<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        self.deserialize_map(visitor)
+    }
+}
+
+#[cfg(test)]
+mod element_serde_tests {
+    use super::*;
+    use crate::types::decimal::Decimal;
+    use crate::types::timestamp::Timestamp;
+    use crate::value::native_reader::NativeElementReader;
+    use crate::value::reader::ElementReader;
+    use crate::value::to_element;
+    use rstest::*;
+    use serde::Serialize;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Point,
+        Circle(u32),
+        Line(i32, i32),
+        Rect { width: u64, height: u64 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config<'a> {
+        name: &'a str,
+        port: u16,
+        ratio: f32,
+        shapes: Vec<Shape>,
+        fallback: Option<Box<Config<'a>>>,
+        #[serde(with = "serde_bytes")]
+        key: &'a [u8],
+        updated: Timestamp,
+        budget: Decimal,
+        limits: HashMap<String, u64>,
+    }
+
+    fn config() -> Config<'static> {
+        Config {
+            name: "primary",
+            port: 8080,
+            ratio: 0.25,
+            shapes: vec![Shape::Point, Shape::Circle(1)],
+            fallback: Some(Box::new(Config {
+                name: "secondary",
+                port: 8081,
+                ratio: 1.5,
+                shapes: vec![],
+                fallback: None,
+                key: b"",
+                updated: Timestamp::with_year(2020).build().unwrap(),
+                budget: Decimal::negative_zero(),
+                limits: HashMap::new(),
+            })),
+            key: b"\x00\xff",
+            updated: Timestamp::with_ymd(2021, 6, 1)
+                .with_hour_and_minute(12, 30)
+                .build_at_unknown_offset()
+                .unwrap(),
+            budget: Decimal::new(12345, -2),
+            limits: vec![("u".to_string(), u64::MAX)].into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn round_trip() -> IonResult<()> {
+        let expected = config();
+        let element = to_element(&expected)?;
+        let actual: Config = from_element(&element)?;
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_non_string_map_keys() -> IonResult<()> {
+        let ids: HashMap<u32, String> = vec![(1, "one".to_string()), (20, "twenty".to_string())]
+            .into_iter()
+            .collect();
+        let element = to_element(&ids)?;
+        assert_eq!(ids, from_element::<HashMap<u32, String>>(&element)?);
+        let flags: BTreeMap<bool, char> = vec![(false, 'n'), (true, 'y')].into_iter().collect();
+        let element = to_element(&flags)?;
+        assert_eq!(flags, from_element::<BTreeMap<bool, char>>(&element)?);
+        let initials: BTreeMap<char, i64> = vec![('a', -1), ('b', 2)].into_iter().collect();
+        let element = to_element(&initials)?;
+        assert_eq!(initials, from_element::<BTreeMap<char, i64>>(&element)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unit_variant(Shape::Point, "Point")]
+    #[case::newtype_variant(Shape::Circle(2), "Circle::2")]
+    #[case::tuple_variant(Shape::Line(-1, 1), "Line::[-1, 1]")]
+    #[case::struct_variant(Shape::Rect { width: 3, height: 4 }, "Rect::{height: 4, width: 3}")]
+    fn enums_are_annotations(#[case] shape: Shape, #[case] ion: &str) -> IonResult<()> {
+        let element = NativeElementReader.read_one(ion.as_bytes())?;
+        assert_eq!(element, to_element(&shape)?);
+        assert_eq!(shape, from_element(&element)?);
+        Ok(())
+    }
+
+    #[test]
+    fn native_ion_types() -> IonResult<()> {
+        let element = to_element(&config())?;
+        let fields = element.as_struct().unwrap();
+        let ion_type = |name: &str| fields.get(name).unwrap().ion_type();
+        assert_eq!(IonType::List, ion_type("shapes"));
+        assert_eq!(IonType::Timestamp, ion_type("updated"));
+        assert_eq!(IonType::Decimal, ion_type("budget"));
+        assert_eq!(IonType::Blob, ion_type("key"));
+        assert_eq!(IonType::Struct, ion_type("limits"));
+        let limits = fields.get("limits").unwrap().as_struct().unwrap();
+        assert_eq!(IonType::Integer, limits.get("u").unwrap().ion_type());
+        Ok(())
+    }
+
+    #[test]
+    fn read_patched_element() -> IonResult<()> {
+        // Elements read from Ion text can be bound to types, borrowing their text.
+        let element = NativeElementReader.read_one(
+            br#"
+                {
+                    name: primary,
+                    port: 9090,
+                    ratio: 2.5,
+                    shapes: [Rect::{width: 1, height: 2}],
+                    fallback: null.struct,
+                    key: {{AQI=}},
+                    updated: 2021-06-01T12:30Z,
+                    budget: 1.50,
+                    limits: {},
+                    unknown: (ignored),
+                }
+            "#,
+        )?;
+        let config: Config = from_element(&element)?;
+        assert_eq!("primary", config.name);
+        assert_eq!(9090, config.port);
+        assert_eq!(2.5, config.ratio);
+        assert_eq!(
+            vec![Shape::Rect {
+                width: 1,
+                height: 2
+            }],
+            config.shapes
+        );
+        assert_eq!(None, config.fallback);
+        assert_eq!(&[1, 2], config.key);
+        assert_eq!(Decimal::new(150, -2), config.budget);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::missing_variant_contents(b"\"Circle\"")]
+    #[case::unknown_variant(b"Hexagon::1")]
+    #[case::wrong_variant_contents(b"Circle::\"one\"")]
+    #[case::null(b"null")]
+    fn from_element_fails(#[case] ion: &[u8]) -> IonResult<()> {
+        let element = NativeElementReader.read_one(ion)?;
+        assert!(from_element::<Shape>(&element).is_err());
+        Ok(())
+    }
+}
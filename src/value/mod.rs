@@ -93,6 +93,25 @@
 //! # }
 //! ```
 //!
+//! The [`ElementReader`](reader::ElementReader) returned by [`element_reader`](reader::element_reader)
+//! is backed by Ion C. [`NativeElementReader`](native_reader::NativeElementReader) implements the
+//! same trait using this crate's own binary and text readers:
+//!
+//! ```
+//! # use ion_rs::result::IonResult;
+//! # use ion_rs::value::{Element, IntAccess};
+//! # use ion_rs::value::native_reader::NativeElementReader;
+//! # use ion_rs::value::reader::ElementReader;
+//! # fn main() -> IonResult<()> {
+//! #
+//! let elems = NativeElementReader.read_all(b"1 2 3")?;
+//! assert_eq!(3, elems.len());
+//! assert_eq!(2, elems[1].as_i64().unwrap());
+//! #
+//! #    Ok(())
+//! # }
+//! ```
+//!
 //! To serialize data, users can use the [`ElementWriter`](writer::ElementWriter) trait to serialize data
 //! from [`Element`] to binary or text Ion:
 //!
@@ -203,19 +222,33 @@
 //! [simd-json-value]: https://docs.rs/simd-json/latest/simd_json/value/index.html
 //! [serde-json-value]: https://docs.serde.rs/serde_json/value/enum.Value.html
 
+use crate::ion_eq::IonEq;
+use crate::ion_ord::IonOrd;
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
 use crate::IonType;
 use num_bigint::BigInt;
 use num_traits::ToPrimitive;
+use std::cmp::Ordering;
 use std::fmt::Debug;
 
 pub mod borrowed;
+#[cfg(feature = "serde")]
+mod de;
+pub mod native_reader;
+pub mod native_writer;
 pub mod owned;
 pub mod reader;
+#[cfg(feature = "serde")]
+mod ser;
 pub mod writer;
 
+#[cfg(feature = "serde")]
+pub use self::de::from_element;
+#[cfg(feature = "serde")]
+pub use self::ser::to_element;
+
 /// The shared symbol table source of a given [`SymbolToken`].
 pub trait ImportSource: Debug + PartialEq {
     /// The name of the shared symbol table that the token is from.
@@ -279,7 +312,8 @@ pub trait ImportSource: Debug + PartialEq {
 /// data is read from a context in which a shared symbol table is not available and
 /// is mixed with data was resolved with the shared symbol table being present.
 /// Such a context implies more than one symbol table catalog in use by an application
-/// which is not a typical (but certainly valid) usage pattern.
+/// which is not a typical (but certainly valid) usage pattern. Use [`IonEq`] to compare
+/// values with the data model's definition of equivalence.
 ///
 /// [symbol-data-model]: https://amzn.github.io/ion-docs/docs/symbols.html#data-model
 pub trait SymbolToken: Debug + PartialEq {
@@ -416,6 +450,25 @@ impl PartialEq for AnyInt {
 
 impl Eq for AnyInt {}
 
+impl IonEq for AnyInt {
+    fn ion_eq(&self, other: &Self) -> bool {
+        // Ion integers are equivalent if their values are equal, regardless of representation.
+        self == other
+    }
+}
+
+impl IonOrd for AnyInt {
+    fn ion_cmp(&self, other: &Self) -> Ordering {
+        use AnyInt::*;
+        match (self, other) {
+            (I64(my_i64), I64(other_i64)) => my_i64.cmp(other_i64),
+            (BigInt(my_bi), BigInt(other_bi)) => my_bi.cmp(other_bi),
+            (I64(my_i64), BigInt(other_bi)) => num_bigint::BigInt::from(*my_i64).cmp(other_bi),
+            (BigInt(my_bi), I64(other_i64)) => my_bi.cmp(&num_bigint::BigInt::from(*other_i64)),
+        }
+    }
+}
+
 /// Represents a either a borrowed or owned Ion datum.  There are/will be specific APIs for
 /// _borrowed_ and _owned_ implementations, but this trait unifies operations on either.
 pub trait Element
@@ -0,0 +1,601 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Provides a pure-Rust implementation of [`ElementReader`] that is built on top of the
+//! [`Reader`] and does not depend on Ion C, along with a [`JsonElementReader`] that reads JSON
+//! input into the same [`OwnedElement`] trees.
+
+use crate::binary::constants::v1_0::IVM;
+use crate::cursor::{Cursor, RawSymbolToken};
+use crate::result::{decoding_error, IonResult};
+use crate::types::decimal::Decimal;
+use crate::types::SymbolId;
+use crate::value::owned::{
+    local_sid_token, text_token, OwnedElement, OwnedSequence, OwnedStruct, OwnedSymbolToken,
+    OwnedValue,
+};
+use crate::value::reader::ElementReader;
+use crate::value::AnyInt;
+use crate::{BinaryIonCursor, IonType, Reader, TextIonCursor};
+use num_bigint::BigInt;
+use std::convert::TryInto;
+use std::io;
+
+/// An [`ElementReader`] that materializes [`OwnedElement`] trees using this crate's native binary
+/// and text readers. Input that begins with an Ion version marker is read as binary Ion; all
+/// other input is read as text Ion.
+pub struct NativeElementReader;
+
+impl ElementReader for NativeElementReader {
+    fn iterate_over<'a, 'b>(
+        &'a self,
+        data: &'b [u8],
+    ) -> IonResult<Box<dyn Iterator<Item = IonResult<OwnedElement>> + 'b>> {
+        if data.starts_with(&IVM) {
+            let cursor = BinaryIonCursor::new(io::Cursor::new(data));
+            return Ok(Box::new(NativeElementIterator::new(Reader::new(cursor))));
+        }
+        let cursor = TextIonCursor::new(data);
+        Ok(Box::new(NativeElementIterator::new(Reader::new(cursor))))
+    }
+}
+
+/// Configures how a [`JsonElementReader`] materializes JSON numbers that have a fractional part
+/// (for example, `2.5`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FractionalNumbers {
+    /// Fractional numbers become Ion decimals, preserving their exact value and precision.
+    Decimal,
+    /// Fractional numbers become Ion floats.
+    Float,
+}
+
+impl Default for FractionalNumbers {
+    fn default() -> Self {
+        FractionalNumbers::Decimal
+    }
+}
+
+/// An [`ElementReader`] that materializes [`OwnedElement`] trees from JSON input. The input may
+/// contain any number of top-level JSON values, so newline-delimited JSON is read as a stream.
+///
+/// Because JSON is a subset of Ion text, JSON values map onto Ion types directly: numbers without
+/// a fraction or exponent become integers, numbers with an exponent (`1e5`) become floats, and
+/// numbers with a fraction become decimals or floats as configured by [`FractionalNumbers`].
+/// Values that use Ion-only constructs (annotations, symbols, s-expressions, timestamps, lobs,
+/// typed nulls, `nan` and `+inf`/`-inf`) are reported as errors. Lexical extensions that do not
+/// change the data model, like comments and unquoted field names, are accepted.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonElementReader {
+    fractional_numbers: FractionalNumbers,
+}
+
+impl JsonElementReader {
+    pub fn new(fractional_numbers: FractionalNumbers) -> Self {
+        JsonElementReader { fractional_numbers }
+    }
+}
+
+impl ElementReader for JsonElementReader {
+    fn iterate_over<'a, 'b>(
+        &'a self,
+        data: &'b [u8],
+    ) -> IonResult<Box<dyn Iterator<Item = IonResult<OwnedElement>> + 'b>> {
+        let cursor = TextIonCursor::new(data);
+        Ok(Box::new(NativeElementIterator::json(
+            Reader::new(cursor),
+            self.fractional_numbers,
+        )))
+    }
+}
+
+/// Yields each top-level value read by the wrapped [`Reader`] as an [`OwnedElement`].
+struct NativeElementIterator<C: Cursor> {
+    reader: Reader<C>,
+    done: bool,
+    // If set, only values that could have been read from JSON are accepted.
+    json: Option<FractionalNumbers>,
+}
+
+impl<C: Cursor> NativeElementIterator<C> {
+    fn new(reader: Reader<C>) -> Self {
+        NativeElementIterator {
+            reader,
+            done: false,
+            json: None,
+        }
+    }
+
+    fn json(reader: Reader<C>, fractional_numbers: FractionalNumbers) -> Self {
+        NativeElementIterator {
+            reader,
+            done: false,
+            json: Some(fractional_numbers),
+        }
+    }
+
+    /// Materializes the value on which the reader is currently positioned.
+    fn materialize(&mut self, ion_type: IonType, is_null: bool) -> IonResult<OwnedElement> {
+        use OwnedValue::*;
+
+        if let Some(fractional_numbers) = self.json {
+            return self.materialize_json(ion_type, is_null, fractional_numbers);
+        }
+
+        let annotations = self.materialize_annotations()?;
+
+        let value = if is_null {
+            Null(ion_type)
+        } else {
+            match ion_type {
+                IonType::Null => Null(ion_type),
+                IonType::Boolean => Boolean(self.expect_value(Reader::read_bool)?),
+                IonType::Integer => Integer(self.expect_value(Reader::read_any_int)?),
+                IonType::Float => Float(self.expect_value(Reader::read_f64)?),
+                IonType::Decimal => Decimal(self.expect_value(Reader::read_decimal)?),
+                IonType::Timestamp => Timestamp(self.expect_value(Reader::read_timestamp)?),
+                IonType::Symbol => Symbol(self.materialize_symbol()?),
+                IonType::String => String(self.expect_value(Reader::read_string)?),
+                IonType::Clob => Clob(self.expect_value(Reader::read_clob_bytes)?),
+                IonType::Blob => Blob(self.expect_value(Reader::read_blob_bytes)?),
+                IonType::List => List(self.materialize_sequence()?),
+                IonType::SExpression => SExpression(self.materialize_sequence()?),
+                IonType::Struct => Struct(self.materialize_struct()?),
+            }
+        };
+
+        Ok(OwnedElement::new(annotations, value))
+    }
+
+    /// Materializes the value on which the reader is currently positioned, rejecting any value
+    /// that cannot be expressed in JSON.
+    fn materialize_json(
+        &mut self,
+        ion_type: IonType,
+        is_null: bool,
+        fractional_numbers: FractionalNumbers,
+    ) -> IonResult<OwnedElement> {
+        use OwnedValue::*;
+
+        if self.reader.annotations().next().is_some() {
+            return decoding_error("JSON values cannot have annotations.");
+        }
+
+        let value = match (ion_type, is_null) {
+            (IonType::Null, _) => Null(IonType::Null),
+            (_, true) => {
+                return decoding_error(format!("JSON does not support typed nulls: {:?}", ion_type))
+            }
+            (IonType::Boolean, _) => Boolean(self.expect_value(Reader::read_bool)?),
+            (IonType::Integer, _) => Integer(self.expect_value(Reader::read_any_int)?),
+            (IonType::Float, _) => {
+                let value = self.expect_value(Reader::read_f64)?;
+                if !value.is_finite() {
+                    return decoding_error(format!("JSON does not support the float {}", value));
+                }
+                Float(value)
+            }
+            (IonType::Decimal, _) => {
+                let value = self.expect_value(Reader::read_decimal)?;
+                match fractional_numbers {
+                    FractionalNumbers::Decimal => Decimal(value),
+                    FractionalNumbers::Float => {
+                        let value = decimal_to_f64(value)?;
+                        if !value.is_finite() {
+                            return decoding_error(
+                                "Found a JSON number that is too large for a float.",
+                            );
+                        }
+                        Float(value)
+                    }
+                }
+            }
+            (IonType::String, _) => String(self.expect_value(Reader::read_string)?),
+            (IonType::List, _) => List(self.materialize_sequence()?),
+            (IonType::Struct, _) => Struct(self.materialize_struct()?),
+            (ion_type, _) => {
+                return decoding_error(format!("JSON does not support Ion {:?} values", ion_type))
+            }
+        };
+
+        Ok(value.into())
+    }
+
+    /// Calls the provided `read_*` method, treating a value of the wrong type as an error.
+    fn expect_value<T, F>(&mut self, read: F) -> IonResult<T>
+    where
+        F: FnOnce(&mut Reader<C>) -> IonResult<Option<T>>,
+    {
+        match read(&mut self.reader)? {
+            Some(value) => Ok(value),
+            None => decoding_error(format!(
+                "Could not read the current value as a {:?}",
+                self.reader.ion_type()
+            )),
+        }
+    }
+
+    fn materialize_symbol(&mut self) -> IonResult<OwnedSymbolToken> {
+        let sid = match self.reader.read_raw_symbol()? {
+            Some(RawSymbolToken::SymbolId(sid)) => sid,
+            Some(RawSymbolToken::Text(text)) => return Ok(text_token(text)),
+            None => return decoding_error("Could not read the current value as a symbol"),
+        };
+        self.symbol_token_for_sid(sid)
+    }
+
+    /// Advances the reader to the next top-level value. JSON has no system values, so in JSON
+    /// mode an Ion version marker or a local symbol table is an error rather than a directive.
+    fn next_top_level_value(&mut self) -> IonResult<Option<(IonType, bool)>> {
+        if self.json.is_some() {
+            return self.reader.next_without_system_values();
+        }
+        self.reader.next()
+    }
+
+    fn materialize_annotations(&self) -> IonResult<Vec<OwnedSymbolToken>> {
+        self.reader
+            .raw_annotations()
+            .map(|annotation| self.symbol_token(annotation))
+            .collect()
+    }
+
+    fn materialize_sequence(&mut self) -> IonResult<OwnedSequence> {
+        let mut children = Vec::new();
+        self.reader.step_in()?;
+        while let Some((ion_type, is_null)) = self.reader.next()? {
+            children.push(self.materialize(ion_type, is_null)?);
+        }
+        self.reader.step_out()?;
+        Ok(children.into_iter().collect())
+    }
+
+    fn materialize_struct(&mut self) -> IonResult<OwnedStruct> {
+        let mut fields = vec![];
+        self.reader.step_in()?;
+        while let Some((ion_type, is_null)) = self.reader.next()? {
+            let token = match self.reader.raw_field_name() {
+                Some(field_name) => self.symbol_token(field_name)?,
+                None => return decoding_error("Found a struct field without a field name."),
+            };
+            let elem = self.materialize(ion_type, is_null)?;
+            fields.push((token, elem));
+        }
+        self.reader.step_out()?;
+        Ok(fields.into_iter().collect())
+    }
+
+    fn symbol_token(&self, token: RawSymbolToken) -> IonResult<OwnedSymbolToken> {
+        match token {
+            RawSymbolToken::SymbolId(sid) => self.symbol_token_for_sid(sid),
+            RawSymbolToken::Text(text) => Ok(text_token(text)),
+        }
+    }
+
+    /// Resolves a symbol ID against the current symbol table. Symbols whose text is unknown
+    /// (`$0`, or symbols imported from a shared table that is not in the catalog) are
+    /// materialized with only their local symbol ID.
+    fn symbol_token_for_sid(&self, sid: SymbolId) -> IonResult<OwnedSymbolToken> {
+        let symbol_table = self.reader.symbol_table();
+        match symbol_table.text_for(sid) {
+            Some(text) => Ok(text_token(text)),
+            None if symbol_table.sid_is_valid(sid) => Ok(local_sid_token(sid)),
+            None => decoding_error(format!(
+                "Symbol ID ${} is not defined in the current symbol table.",
+                sid
+            )),
+        }
+    }
+}
+
+/// Converts a [`Decimal`] to the nearest `f64`.
+pub(crate) fn decimal_to_f64(decimal: Decimal) -> IonResult<f64> {
+    if decimal.coefficient.is_negative_zero() {
+        return Ok(-0f64);
+    }
+    let exponent = decimal.exponent;
+    let coefficient: BigInt = decimal.coefficient.try_into()?;
+    // Rust's float parsing is correctly rounded, unlike scaling by a power of ten.
+    match format!("{}e{}", coefficient, exponent).parse() {
+        Ok(value) => Ok(value),
+        Err(e) => decoding_error(format!("Could not convert decimal to a float: {}", e)),
+    }
+}
+
+impl<C: Cursor> Iterator for NativeElementIterator<C> {
+    type Item = IonResult<OwnedElement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // if we previously returned an error, we're done
+        if self.done {
+            return None;
+        }
+        let result = match self.next_top_level_value() {
+            Ok(Some((ion_type, is_null))) => self.materialize(ion_type, is_null),
+            Ok(None) => {
+                self.done = true;
+                return None;
+            }
+            Err(e) => Err(e),
+        };
+        if result.is_err() {
+            // a failure means the iterator is done
+            self.done = true;
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod native_reader_tests {
+    use super::*;
+    use crate::value::owned::OwnedValue::*;
+    use crate::value::writer::{ElementWriter, Format, TextKind};
+    use crate::value::{Element, IntAccess};
+    use rstest::*;
+
+    #[rstest]
+    #[case::scalars(
+        br#"
+            null.int true 5 2.5e0 1d1 2020-02-27T14:16:33Z foo "bar"
+        "#,
+        vec![
+            Null(IonType::Integer),
+            Boolean(true),
+            Integer(AnyInt::I64(5)),
+            Float(2.5),
+            Decimal(crate::types::decimal::Decimal::new(1, 1)),
+            Timestamp(crate::types::timestamp::Timestamp::with_ymd(2020, 2, 27).with_hms(14, 16, 33).build_at_offset(0).unwrap()),
+            Symbol("foo".into()),
+            String("bar".into()),
+        ].into_iter().map(|v| v.into()).collect(),
+    )]
+    #[case::big_ints(
+        b"18446744073709551616 -9223372036854775808",
+        vec![
+            Integer(AnyInt::BigInt(num_bigint::BigInt::from(u64::MAX) + 1)),
+            Integer(AnyInt::I64(i64::MIN)),
+        ].into_iter().map(|v| v.into()).collect(),
+    )]
+    #[case::precision(
+        b"-0d0 0.10 2020T 2020-02-27T14:16-00:00",
+        vec![
+            Decimal(crate::types::decimal::Decimal::negative_zero()),
+            Decimal(crate::types::decimal::Decimal::new(10, -2)),
+            Timestamp(crate::types::timestamp::Timestamp::with_year(2020).build().unwrap()),
+            Timestamp(
+                crate::types::timestamp::Timestamp::with_ymd(2020, 2, 27)
+                    .with_hour_and_minute(14, 16)
+                    .build_at_unknown_offset()
+                    .unwrap()
+            ),
+        ].into_iter().map(|v| v.into()).collect(),
+    )]
+    #[case::lobs(
+        br#"
+            {{"moo"}} {{bW9v}}
+        "#,
+        vec![
+            Clob(b"moo".to_vec()),
+            Blob(b"moo".to_vec()),
+        ].into_iter().map(|v| v.into()).collect(),
+    )]
+    #[case::containers(
+        br#"
+            a::[1, (b c)] {d: e::2}
+        "#,
+        vec![
+            OwnedElement::new(
+                vec![text_token("a")],
+                List(vec![
+                    Integer(AnyInt::I64(1)).into(),
+                    SExpression(vec![
+                        Symbol("b".into()).into(),
+                        Symbol("c".into()).into(),
+                    ].into_iter().collect()).into(),
+                ].into_iter().collect()),
+            ),
+            Struct(vec![
+                (text_token("d"), OwnedElement::new(vec![text_token("e")], Integer(AnyInt::I64(2)))),
+            ].into_iter().collect()).into(),
+        ],
+    )]
+    #[case::local_symbols(
+        br#"
+            $ion_symbol_table::{symbols: ["foo", "bar"]}
+            $10::{$11: $10}
+        "#,
+        vec![
+            OwnedElement::new(
+                vec![text_token("foo")],
+                Struct(vec![
+                    (text_token("bar"), Symbol("foo".into()).into()),
+                ].into_iter().collect()),
+            ),
+        ],
+    )]
+    #[case::unknown_text(
+        br#"
+            $0::{$0: $0}
+            $ion_symbol_table::{imports: [{name: "missing", version: 1, max_id: 2}]}
+            $10
+        "#,
+        vec![
+            OwnedElement::new(
+                vec![local_sid_token(0)],
+                Struct(vec![
+                    (local_sid_token(0), Symbol(local_sid_token(0)).into()),
+                ].into_iter().collect()),
+            ),
+            Symbol(local_sid_token(10)).into(),
+        ],
+    )]
+    #[case::binary(
+        // $ion_1_0 [1, 'name']
+        &[0xE0, 0x01, 0x00, 0xEA, 0xB3, 0x21, 0x01, 0x71, 0x04],
+        vec![
+            List(vec![
+                Integer(AnyInt::I64(1)).into(),
+                Symbol("name".into()).into(),
+            ].into_iter().collect()).into(),
+        ],
+    )]
+    fn read_and_compare(
+        #[case] input: &[u8],
+        #[case] expected: Vec<OwnedElement>,
+    ) -> IonResult<()> {
+        let actual = NativeElementReader.read_all(input)?;
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::malformed_text(b"[1, 2")]
+    #[case::undefined_symbol(b"$10")]
+    #[case::undefined_field_name(b"{$10: 1}")]
+    #[case::undefined_annotation(b"$10::1")]
+    fn read_fails(#[case] input: &[u8]) {
+        let results: Vec<_> = NativeElementReader.iterate_over(input).unwrap().collect();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
+
+    #[test]
+    fn read_one() -> IonResult<()> {
+        let element = NativeElementReader.read_one(b"5")?;
+        assert_eq!(element.as_i64(), Some(5));
+        assert!(NativeElementReader.read_one(b"5 6").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn read_json_lines() -> IonResult<()> {
+        let input = br#"
+            {"id": 1, "tags": ["a", "b"], "score": 2.50, "ratio": 1e-1, "nothing": null}
+            {"id": 18446744073709551616, "tags": [], "score": -0.0, "ratio": 5E2, "nothing": false}
+        "#;
+        let expected: Vec<OwnedElement> = vec![
+            Struct(
+                vec![
+                    (text_token("id"), Integer(AnyInt::I64(1)).into()),
+                    (
+                        text_token("tags"),
+                        List(
+                            vec![String("a".into()).into(), String("b".into()).into()]
+                                .into_iter()
+                                .collect(),
+                        )
+                        .into(),
+                    ),
+                    (
+                        text_token("score"),
+                        Decimal(crate::types::decimal::Decimal::new(250, -2)).into(),
+                    ),
+                    (text_token("ratio"), Float(0.1).into()),
+                    (text_token("nothing"), Null(IonType::Null).into()),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .into(),
+            Struct(
+                vec![
+                    (
+                        text_token("id"),
+                        Integer(AnyInt::BigInt(num_bigint::BigInt::from(u64::MAX) + 1)).into(),
+                    ),
+                    (text_token("tags"), List(OwnedSequence::new(vec![])).into()),
+                    (
+                        text_token("score"),
+                        Decimal(crate::types::decimal::Decimal::negative_zero_with_exponent(
+                            -1,
+                        ))
+                        .into(),
+                    ),
+                    (text_token("ratio"), Float(500.0).into()),
+                    (text_token("nothing"), Boolean(false).into()),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .into(),
+        ];
+        assert_eq!(expected, JsonElementReader::default().read_all(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_json_unicode_escapes() -> IonResult<()> {
+        let input = br#"{"\u0061": "\u00e9 \uD83D\uDE00"}"#;
+        let expected: OwnedElement = Struct(
+            vec![(text_token("a"), String("\u{e9} \u{1F600}".into()).into())]
+                .into_iter()
+                .collect(),
+        )
+        .into();
+        assert_eq!(expected, JsonElementReader::default().read_one(input)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::fraction(b"2.5", 2.5)]
+    #[case::trailing_zero(b"0.10", 0.1)]
+    #[case::negative_zero(b"-0.0", -0.0)]
+    #[case::inexact(b"0.3", 0.3)]
+    #[case::exponent(b"1.5e3", 1500.0)]
+    fn read_json_fractions_as_floats(#[case] input: &[u8], #[case] expected: f64) -> IonResult<()> {
+        let element = JsonElementReader::new(FractionalNumbers::Float).read_one(input)?;
+        let actual = element.as_f64().unwrap();
+        assert_eq!(expected, actual);
+        assert_eq!(expected.is_sign_negative(), actual.is_sign_negative());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::annotation(b"a::1")]
+    #[case::nested_annotation(b"[1, a::2]")]
+    #[case::symbol(b"foo")]
+    #[case::field_symbol(b"{\"a\": foo}")]
+    #[case::sexp(b"(1 2)")]
+    #[case::timestamp(b"2021-03-04T")]
+    #[case::blob(b"{{bW9v}}")]
+    #[case::clob(b"{{\"moo\"}}")]
+    #[case::typed_null(b"null.int")]
+    #[case::nan(b"nan")]
+    #[case::infinity(b"-inf")]
+    #[case::binary(&[0xE0, 0x01, 0x00, 0xEA, 0x21, 0x01])]
+    #[case::unpaired_surrogate(br#""\uD83D""#)]
+    #[case::version_marker(b"$ion_1_0 1")]
+    #[case::symbol_table(b"$ion_symbol_table::{symbols: [\"a\"]} 1")]
+    fn read_json_fails(#[case] input: &[u8]) {
+        let results: Vec<_> = JsonElementReader::default()
+            .iterate_over(input)
+            .unwrap()
+            .collect();
+        assert!(results.iter().any(|r| r.is_err()));
+    }
+
+    #[test]
+    fn read_json_fraction_too_large_for_a_float_fails() {
+        let input = format!("{}.5", "9".repeat(400));
+        let reader = JsonElementReader::new(FractionalNumbers::Float);
+        assert!(reader.read_one(input.as_bytes()).is_err());
+        // The same number can still be read as a decimal.
+        assert!(JsonElementReader::default()
+            .read_one(input.as_bytes())
+            .is_ok());
+    }
+
+    #[rstest]
+    #[case::compact(Format::Json(TextKind::Compact))]
+    #[case::pretty(Format::Json(TextKind::Pretty))]
+    fn read_json_round_trip(#[case] format: Format) -> IonResult<()> {
+        let input = br#"{"a": [1, 2.5, "three", true, null], "b": {"c": 1e0}} [] "x""#;
+        let elements = JsonElementReader::default().read_all(input)?;
+        assert_eq!(3, elements.len());
+        let mut writer = format.element_writer_for(Vec::new())?;
+        writer.write_all(&elements)?;
+        let output = writer.finish()?;
+        assert_eq!(elements, JsonElementReader::default().read_all(&output)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,385 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Provides pure-Rust implementations of [`ElementWriter`] that are built on top of the
+//! [`BinaryWriter`] and the [`TextWriter`] and do not depend on Ion C.
+
+use crate::binary::binary_writer::BinaryWriter;
+use crate::cursor::RawSymbolToken;
+use crate::result::{illegal_operation, IonResult};
+use crate::text::writer::TextWriter;
+use crate::value::writer::ElementWriter;
+use crate::value::{AnyInt, Element, Sequence, Struct, SymbolToken};
+use crate::writer::IonWriter;
+use crate::IonType;
+use std::io::Write;
+
+// Writes the provided element (and any children) using the provided IonWriter. Symbols whose
+// text is unknown are written by their local symbol ID.
+fn write_element<I: IonWriter, E: Element>(
+    writer: &mut I,
+    field_name: Option<RawSymbolToken>,
+    element: &E,
+) -> IonResult<()> {
+    let annotations = element
+        .annotations()
+        .map(raw_symbol_token)
+        .collect::<IonResult<Vec<RawSymbolToken>>>()?;
+    writer.set_annotations(&annotations)?;
+    if let Some(field_name) = field_name {
+        writer.set_field_name(field_name)?;
+    }
+
+    let ion_type = element.ion_type();
+    if element.is_null() {
+        return writer.write_null(ion_type);
+    }
+    match ion_type {
+        IonType::Null => writer.write_null(ion_type),
+        IonType::Boolean => writer.write_bool(try_to!(element.as_bool())),
+        IonType::Integer => match try_to!(element.as_any_int()) {
+            AnyInt::I64(value) => writer.write_i64(*value),
+            AnyInt::BigInt(value) => writer.write_big_int(value),
+        },
+        IonType::Float => writer.write_f64(try_to!(element.as_f64())),
+        IonType::Decimal => writer.write_decimal(try_to!(element.as_decimal())),
+        IonType::Timestamp => writer.write_timestamp(try_to!(element.as_timestamp())),
+        IonType::Symbol => writer.write_symbol(raw_symbol_token(try_to!(element.as_sym()))?),
+        IonType::String => writer.write_string(try_to!(element.as_str())),
+        IonType::Clob => writer.write_clob(try_to!(element.as_bytes())),
+        IonType::Blob => writer.write_blob(try_to!(element.as_bytes())),
+        IonType::List | IonType::SExpression => {
+            writer.step_in(ion_type)?;
+            for child in try_to!(element.as_sequence()).iter() {
+                write_element(writer, None, child)?;
+            }
+            writer.step_out()
+        }
+        IonType::Struct => {
+            writer.step_in(ion_type)?;
+            for (field_name, child) in try_to!(element.as_struct()).iter() {
+                write_element(writer, Some(raw_symbol_token(field_name)?), child)?;
+            }
+            writer.step_out()
+        }
+    }
+}
+
+// Converts a SymbolToken into the RawSymbolToken that an IonWriter expects, preferring its text.
+fn raw_symbol_token<T: SymbolToken>(token: &T) -> IonResult<RawSymbolToken> {
+    match (token.text(), token.local_sid()) {
+        (Some(text), _) => Ok(RawSymbolToken::Text(text)),
+        (None, Some(sid)) => Ok(RawSymbolToken::SymbolId(sid)),
+        (None, None) => illegal_operation(format!(
+            "Could not serialize a symbol with neither text nor a local symbol ID: {:?}",
+            token
+        )),
+    }
+}
+
+/// An [`ElementWriter`] that encodes binary Ion using a [`BinaryWriter`].
+///
+/// Each top-level value is flushed as soon as it is written, so any symbol text that it
+/// introduces is declared in a local symbol table immediately before it.
+pub struct NativeBinaryElementWriter<W: Write> {
+    writer: BinaryWriter<W>,
+}
+
+impl<W: Write> NativeBinaryElementWriter<W> {
+    /// Creates a new writer that will write its encoded output to the provided io::Write sink.
+    pub fn new(out: W) -> Self {
+        NativeBinaryElementWriter {
+            writer: BinaryWriter::new(out),
+        }
+    }
+}
+
+impl<W: Write> ElementWriter for NativeBinaryElementWriter<W> {
+    type Output = W;
+
+    fn write<E: Element>(&mut self, element: &E) -> IonResult<()> {
+        write_element(&mut self.writer, None, element)?;
+        self.writer.flush()
+    }
+
+    fn finish(mut self) -> IonResult<Self::Output> {
+        self.writer.flush()?;
+        Ok(self.writer.into_output())
+    }
+}
+
+/// An [`ElementWriter`] that encodes text Ion using a [`TextWriter`].
+pub struct NativeTextElementWriter<W: Write> {
+    writer: TextWriter<W>,
+}
+
+impl<W: Write> NativeTextElementWriter<W> {
+    /// Creates a new writer that will write its encoded output to the provided io::Write sink.
+    pub fn new(out: W) -> Self {
+        NativeTextElementWriter {
+            writer: TextWriter::new(out),
+        }
+    }
+
+    /// Creates a new writer that will pretty print its encoded output to the provided io::Write
+    /// sink.
+    pub fn pretty(out: W) -> Self {
+        NativeTextElementWriter {
+            writer: TextWriter::pretty(out),
+        }
+    }
+
+    /// Creates a new writer that will down-convert its output to JSON and write it to the
+    /// provided io::Write sink. See [TextWriter::json] for the conversion rules.
+    pub fn json(out: W) -> Self {
+        NativeTextElementWriter {
+            writer: TextWriter::json(out),
+        }
+    }
+
+    /// Like [NativeTextElementWriter::json], but pretty prints the JSON.
+    pub fn pretty_json(out: W) -> Self {
+        NativeTextElementWriter {
+            writer: TextWriter::pretty_json(out),
+        }
+    }
+}
+
+impl<W: Write> ElementWriter for NativeTextElementWriter<W> {
+    type Output = W;
+
+    #[inline]
+    fn write<E: Element>(&mut self, element: &E) -> IonResult<()> {
+        write_element(&mut self.writer, None, element)
+    }
+
+    fn finish(self) -> IonResult<Self::Output> {
+        self.writer.into_output()
+    }
+}
+
+/// An [`ElementWriter`] that writes either binary or text Ion to any io::Write sink, as returned
+/// by [`Format::element_writer_for`](crate::value::writer::Format::element_writer_for).
+pub enum NativeElementWriter<W: Write> {
+    Binary(NativeBinaryElementWriter<W>),
+    Text(NativeTextElementWriter<W>),
+}
+
+impl<W: Write> ElementWriter for NativeElementWriter<W> {
+    type Output = W;
+
+    fn write<E: Element>(&mut self, element: &E) -> IonResult<()> {
+        match self {
+            NativeElementWriter::Binary(writer) => writer.write(element),
+            NativeElementWriter::Text(writer) => writer.write(element),
+        }
+    }
+
+    fn finish(self) -> IonResult<Self::Output> {
+        match self {
+            NativeElementWriter::Binary(writer) => writer.finish(),
+            NativeElementWriter::Text(writer) => writer.finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod native_writer_tests {
+    use super::*;
+    use crate::binary::constants::v1_0::IVM;
+    use crate::value::native_reader::NativeElementReader;
+    use crate::value::owned::OwnedValue::*;
+    use crate::value::owned::{local_sid_token, text_token, OwnedElement};
+    use crate::value::reader::ElementReader;
+    use chrono::{FixedOffset, TimeZone};
+    use rstest::*;
+    use std::str::from_utf8;
+
+    fn test_elements() -> Vec<OwnedElement> {
+        vec![
+            Null(IonType::Struct).into(),
+            Boolean(false).into(),
+            Integer(AnyInt::I64(-17)).into(),
+            Integer(AnyInt::BigInt(-num_bigint::BigInt::from(u128::MAX))).into(),
+            Float(1.5).into(),
+            Decimal(crate::types::decimal::Decimal::new(31, -1)).into(),
+            Timestamp(FixedOffset::east(0).ymd(2021, 3, 4).and_hms(5, 6, 7).into()).into(),
+            Decimal(crate::types::decimal::Decimal::negative_zero_with_exponent(
+                -2,
+            ))
+            .into(),
+            Timestamp(
+                crate::types::timestamp::Timestamp::with_year(2021)
+                    .build()
+                    .unwrap(),
+            )
+            .into(),
+            Timestamp(
+                crate::types::timestamp::Timestamp::with_ymd(2021, 3, 4)
+                    .with_hour_and_minute(5, 6)
+                    .build_at_unknown_offset()
+                    .unwrap(),
+            )
+            .into(),
+            Symbol("foo".into()).into(),
+            String("a \"quoted\"\nstring".into()).into(),
+            Clob(b"moo".to_vec()).into(),
+            Blob(vec![0, 1, 2]).into(),
+            OwnedElement::new(
+                vec![text_token("a"), text_token("b")],
+                List(
+                    vec![
+                        Integer(AnyInt::I64(1)).into(),
+                        SExpression(
+                            vec![Symbol("c".into()).into(), Symbol("foo".into()).into()]
+                                .into_iter()
+                                .collect(),
+                        )
+                        .into(),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+            ),
+            Struct(
+                vec![
+                    (
+                        text_token("d"),
+                        OwnedElement::new(vec![text_token("e")], Integer(AnyInt::I64(2))),
+                    ),
+                    (text_token("foo"), String("bar".into()).into()),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .into(),
+        ]
+    }
+
+    fn write_all<W: ElementWriter<Output = Vec<u8>>>(
+        mut writer: W,
+        elements: &[OwnedElement],
+    ) -> IonResult<Vec<u8>> {
+        writer.write_all(elements)?;
+        writer.finish()
+    }
+
+    #[rstest]
+    #[case::binary(write_all(NativeBinaryElementWriter::new(vec![]), &test_elements()))]
+    #[case::text(write_all(NativeTextElementWriter::new(vec![]), &test_elements()))]
+    #[case::pretty(write_all(NativeTextElementWriter::pretty(vec![]), &test_elements()))]
+    fn round_trip(#[case] output: IonResult<Vec<u8>>) -> IonResult<()> {
+        let output = output?;
+        let actual = NativeElementReader.read_all(&output)?;
+        assert_eq!(test_elements(), actual);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_symbol_tables() -> IonResult<()> {
+        let elements: Vec<OwnedElement> = vec![
+            Symbol("foo".into()).into(),
+            Symbol("foo".into()).into(),
+            Symbol("bar".into()).into(),
+        ];
+        let output = write_all(NativeBinaryElementWriter::new(vec![]), &elements)?;
+        assert!(output.starts_with(&IVM));
+
+        // The second value reuses 'foo', so only two symbol tables should be written.
+        let mut expected = IVM.to_vec();
+        expected.extend_from_slice(&[
+            0xE9, 0x81, 0x83, 0xD6, 0x87, 0xB4, 0x83, b'f', b'o', b'o', // {symbols: ["foo"]}
+            0x71, 0x0A, // $10
+            0x71, 0x0A, // $10
+            0xEC, 0x81, 0x83, 0xD9, 0x86, 0x71, 0x03, 0x87, 0xB4, 0x83, b'b', b'a',
+            b'r', // {imports: $ion_symbol_table, symbols: ["bar"]}
+            0x71, 0x0B, // $11
+        ]);
+        assert_eq!(expected, output);
+        assert_eq!(elements, NativeElementReader.read_all(&output)?);
+        Ok(())
+    }
+
+    #[test]
+    fn text_output() -> IonResult<()> {
+        let elements: Vec<OwnedElement> = vec![
+            OwnedElement::new(vec![text_token("a")], Symbol("b".into())),
+            Struct(
+                vec![(text_token("c"), String("d\te".into()).into())]
+                    .into_iter()
+                    .collect(),
+            )
+            .into(),
+        ];
+        let output = write_all(NativeTextElementWriter::new(vec![]), &elements)?;
+        assert_eq!("a::b\n{c:\"d\\te\",}\n", from_utf8(&output).unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn pretty_text_output() -> IonResult<()> {
+        let elements: Vec<OwnedElement> = vec![
+            OwnedElement::new(vec![text_token("a")], Symbol("b".into())),
+            Struct(
+                vec![
+                    (text_token("c"), String("d".into()).into()),
+                    (text_token("e"), List(vec![].into_iter().collect()).into()),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .into(),
+        ];
+        let output = write_all(NativeTextElementWriter::pretty(vec![]), &elements)?;
+        assert_eq!(
+            "a::b\n{\n  c: \"d\",\n  e: []\n}\n",
+            from_utf8(&output).unwrap()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn text_symbols_without_text() -> IonResult<()> {
+        let elements: Vec<OwnedElement> = vec![OwnedElement::new(
+            vec![local_sid_token(100)],
+            Struct(
+                vec![(local_sid_token(101), Symbol(local_sid_token(102)).into())]
+                    .into_iter()
+                    .collect(),
+            ),
+        )];
+        let output = write_all(NativeTextElementWriter::new(vec![]), &elements)?;
+        assert_eq!("$100::{$101:$102,}\n", from_utf8(&output).unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn binary_symbols_without_text() -> IonResult<()> {
+        // $4, $5 and $6 are the system symbols 'name', 'version' and 'imports'.
+        let elements: Vec<OwnedElement> = vec![OwnedElement::new(
+            vec![local_sid_token(4)],
+            Struct(
+                vec![(local_sid_token(5), Symbol(local_sid_token(6)).into())]
+                    .into_iter()
+                    .collect(),
+            ),
+        )];
+        let output = write_all(NativeBinaryElementWriter::new(vec![]), &elements)?;
+        let expected: OwnedElement = OwnedElement::new(
+            vec![text_token("name")],
+            Struct(
+                vec![(text_token("version"), Symbol("imports".into()).into())]
+                    .into_iter()
+                    .collect(),
+            ),
+        );
+        assert_eq!(expected, NativeElementReader.read_one(&output)?);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_undefined_symbol_ids_fail() {
+        let element = OwnedElement::new(vec![local_sid_token(100)], Integer(AnyInt::I64(1)));
+        let output = write_all(NativeBinaryElementWriter::new(vec![]), &[element]);
+        assert!(output.is_err());
+    }
+}
@@ -6,12 +6,15 @@
 //! ownership of data to do so.
 
 use super::{AnyInt, Element, ImportSource, Sequence, Struct, SymbolToken};
+use crate::ion_eq::{element_ion_eq, IonEq};
+use crate::ion_ord::{element_ion_cmp, IonOrd};
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
 use crate::value::Builder;
 use crate::IonType;
 use num_bigint::BigInt;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::iter::FromIterator;
 use std::rc::Rc;
@@ -228,6 +231,45 @@ impl OwnedSequence {
     pub fn new(children: Vec<OwnedElement>) -> Self {
         Self { children }
     }
+
+    /// Appends an element to the end of the sequence.
+    pub fn push<E: Into<OwnedElement>>(&mut self, element: E) {
+        self.children.push(element.into());
+    }
+
+    /// Removes the last element of the sequence and returns it, or `None` if the sequence is
+    /// empty.
+    pub fn pop(&mut self) -> Option<OwnedElement> {
+        self.children.pop()
+    }
+
+    /// Inserts an element at the given index, shifting all of the elements after it to the
+    /// right.
+    ///
+    /// Panics if `index` is greater than the length of the sequence.
+    pub fn insert<E: Into<OwnedElement>>(&mut self, index: usize, element: E) {
+        self.children.insert(index, element.into());
+    }
+
+    /// Removes and returns the element at the given index, shifting all of the elements after it
+    /// to the left. Returns `None` if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<OwnedElement> {
+        if index >= self.children.len() {
+            return None;
+        }
+        Some(self.children.remove(index))
+    }
+
+    /// Returns a mutable reference to the element at the given index or returns `None` if the
+    /// index is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut OwnedElement> {
+        self.children.get_mut(index)
+    }
+
+    /// Returns an iterator that allows modifying each element of the sequence.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut OwnedElement> {
+        self.children.iter_mut()
+    }
 }
 
 impl FromIterator<OwnedElement> for OwnedSequence {
@@ -277,6 +319,111 @@ pub struct OwnedStruct {
 }
 
 impl OwnedStruct {
+    /// Adds a field to the struct. Any existing fields with the same name are kept, so a struct
+    /// can contain repeated field names.
+    pub fn add_field<K, V>(&mut self, field_name: K, value: V)
+    where
+        K: Into<OwnedSymbolToken>,
+        V: Into<OwnedElement>,
+    {
+        let field_name = field_name.into();
+        let value = value.into();
+        match field_name.text() {
+            Some(text) => self
+                .text_fields
+                .entry(text.into())
+                .or_insert_with(Vec::new)
+                .push((field_name, value)),
+            None => self.no_text_fields.push((field_name, value)),
+        }
+    }
+
+    /// Sets the value of a field, replacing every existing field with the same name. Returns the
+    /// values of the replaced fields.
+    ///
+    /// Field names without text cannot be matched against existing fields, so they are always
+    /// added.
+    pub fn set_field<K, V>(&mut self, field_name: K, value: V) -> Vec<OwnedElement>
+    where
+        K: Into<OwnedSymbolToken>,
+        V: Into<OwnedElement>,
+    {
+        let field_name = field_name.into();
+        let value = value.into();
+        match field_name.text() {
+            Some(text) => self
+                .text_fields
+                .insert(text.into(), vec![(field_name, value)])
+                .into_iter()
+                .flatten()
+                .map(|(_s, v)| v)
+                .collect(),
+            None => {
+                self.no_text_fields.push((field_name, value));
+                Vec::new()
+            }
+        }
+    }
+
+    /// Removes the last field with the given name, which is the field returned by
+    /// [`Struct::get`], and returns its value. Any other fields with the same name are kept.
+    /// Returns `None` if the struct has no field with that name.
+    pub fn remove_field<T: AsRef<str>>(&mut self, field_name: T) -> Option<OwnedElement> {
+        let field_name = field_name.as_ref();
+        let fields = self.text_fields.get_mut(field_name)?;
+        let (_s, value) = fields.pop()?;
+        if fields.is_empty() {
+            self.text_fields.remove(field_name);
+        }
+        Some(value)
+    }
+
+    /// Removes every field with the given name and returns their values.
+    pub fn remove_all<T: AsRef<str>>(&mut self, field_name: T) -> Vec<OwnedElement> {
+        self.text_fields
+            .remove(field_name.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|(_s, v)| v)
+            .collect()
+    }
+
+    /// Returns a mutable reference to the value of the last field with the given name, which is
+    /// the field returned by [`Struct::get`], or returns `None` if the field does not exist.
+    pub fn get_mut<T: AsRef<str>>(&mut self, field_name: T) -> Option<&mut OwnedElement> {
+        self.text_fields
+            .get_mut(field_name.as_ref())?
+            .last_mut()
+            .map(|(_s, v)| v)
+    }
+
+    /// Returns an iterator that allows modifying the values of every field with the given name.
+    pub fn get_all_mut<'a, T: AsRef<str>>(
+        &'a mut self,
+        field_name: T,
+    ) -> Box<dyn Iterator<Item = &'a mut OwnedElement> + 'a> {
+        Box::new(
+            self.text_fields
+                .get_mut(field_name.as_ref())
+                .into_iter()
+                .flat_map(|v| v.iter_mut())
+                .map(|(_s, v)| v),
+        )
+    }
+
+    /// Returns an iterator that allows modifying the value of every field in the struct.
+    pub fn iter_mut<'a>(
+        &'a mut self,
+    ) -> Box<dyn Iterator<Item = (&'a OwnedSymbolToken, &'a mut OwnedElement)> + 'a> {
+        Box::new(
+            self.text_fields
+                .values_mut()
+                .flat_map(|v| v.iter_mut())
+                .chain(self.no_text_fields.iter_mut())
+                .map(|(k, v)| (&*k, v)),
+        )
+    }
+
     fn eq_text_fields(&self, other: &Self) -> bool {
         // check if both the text_fields have same (field_name,value) pairs
         self.text_fields.iter().all(|(key, value)| {
@@ -421,6 +568,54 @@ impl OwnedElement {
     pub fn new(annotations: Vec<OwnedSymbolToken>, value: OwnedValue) -> Self {
         Self { annotations, value }
     }
+
+    /// Returns a reference to the value of this element.
+    pub fn value(&self) -> &OwnedValue {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the value of this element, which can be used to replace
+    /// the value (including its type) in place. The element's annotations are unchanged.
+    pub fn value_mut(&mut self) -> &mut OwnedValue {
+        &mut self.value
+    }
+
+    /// Replaces the annotations of this element.
+    pub fn set_annotations<I: IntoIterator<Item = OwnedSymbolToken>>(&mut self, annotations: I) {
+        self.annotations = annotations.into_iter().collect();
+    }
+
+    /// Adds an annotation after any existing annotations of this element.
+    pub fn add_annotation<T: Into<OwnedSymbolToken>>(&mut self, annotation: T) {
+        self.annotations.push(annotation.into());
+    }
+
+    /// Removes all of the annotations of this element.
+    pub fn clear_annotations(&mut self) {
+        self.annotations.clear();
+    }
+
+    /// Returns a mutable reference to the [`OwnedSequence`] of this element.
+    ///
+    /// This will return `None` in the case that the type is not `sexp`/`list` or
+    /// if the value is any `null`.
+    pub fn as_sequence_mut(&mut self) -> Option<&mut OwnedSequence> {
+        match &mut self.value {
+            OwnedValue::SExpression(seq) | OwnedValue::List(seq) => Some(seq),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the [`OwnedStruct`] of this element.
+    ///
+    /// This will return `None` in the case that the type is not `struct` or the value is
+    /// any `null`.
+    pub fn as_struct_mut(&mut self) -> Option<&mut OwnedStruct> {
+        match &mut self.value {
+            OwnedValue::Struct(structure) => Some(structure),
+            _ => None,
+        }
+    }
 }
 
 impl PartialEq for OwnedElement {
@@ -431,6 +626,18 @@ impl PartialEq for OwnedElement {
 
 impl Eq for OwnedElement {}
 
+impl IonEq for OwnedElement {
+    fn ion_eq(&self, other: &Self) -> bool {
+        element_ion_eq(self, other)
+    }
+}
+
+impl IonOrd for OwnedElement {
+    fn ion_cmp(&self, other: &Self) -> Ordering {
+        element_ion_cmp(self, other)
+    }
+}
+
 impl From<OwnedValue> for OwnedElement {
     fn from(val: OwnedValue) -> Self {
         Self::new(vec![], val)
@@ -634,4 +841,129 @@ mod value_tests {
         // assert if both the element construction creates the same element
         assert_eq!(elem1, elem2);
     }
+
+    fn int(value: i64) -> OwnedElement {
+        OwnedElement::new_i64(value)
+    }
+
+    fn sequence(values: &[i64]) -> OwnedSequence {
+        values.iter().map(|v| int(*v)).collect()
+    }
+
+    #[test]
+    fn mutate_sequence() {
+        let mut seq = sequence(&[1, 2, 3]);
+        seq.push(int(4));
+        assert_eq!(sequence(&[1, 2, 3, 4]), seq);
+        seq.insert(0, int(0));
+        assert_eq!(sequence(&[0, 1, 2, 3, 4]), seq);
+        assert_eq!(Some(int(2)), seq.remove(2));
+        assert_eq!(None, seq.remove(4));
+        assert_eq!(Some(int(4)), seq.pop());
+        *seq.get_mut(0).unwrap() = int(10);
+        assert_eq!(None, seq.get_mut(3));
+        for element in seq.iter_mut() {
+            element.add_annotation("a");
+        }
+        let expected: OwnedSequence = vec![10, 1, 3]
+            .into_iter()
+            .map(|v| int(v).with_annotations(vec![text_token("a")]))
+            .collect();
+        assert_eq!(expected, seq);
+    }
+
+    #[test]
+    fn mutate_struct() {
+        let mut fields: OwnedStruct = vec![("a", int(1)), ("b", int(2)), ("b", int(3))]
+            .into_iter()
+            .collect();
+
+        // add_field keeps repeated names
+        fields.add_field("a", int(4));
+        assert_eq!(
+            vec![&int(1), &int(4)],
+            fields.get_all("a").collect::<Vec<_>>()
+        );
+        fields.add_field(local_sid_token(21), int(5));
+        assert_eq!(5, fields.iter().count());
+
+        // set_field replaces every field with the name
+        assert_eq!(vec![int(2), int(3)], fields.set_field("b", int(6)));
+        assert_eq!(vec![&int(6)], fields.get_all("b").collect::<Vec<_>>());
+        assert!(fields.set_field("c", int(7)).is_empty());
+
+        // remove_field removes the last field with the name
+        assert_eq!(Some(int(4)), fields.remove_field("a"));
+        assert_eq!(Some(&int(1)), fields.get("a"));
+        assert_eq!(None, fields.remove_field("z"));
+        assert_eq!(vec![int(7)], fields.remove_all("c"));
+        assert!(fields.remove_all("c").is_empty());
+
+        *fields.get_mut("a").unwrap() = int(8);
+        assert_eq!(None, fields.get_mut("c"));
+        for value in fields.get_all_mut("b") {
+            value.add_annotation("x");
+        }
+        for (name, value) in fields.iter_mut() {
+            if name.text().is_none() {
+                *value.value_mut() = OwnedValue::Boolean(true);
+            }
+        }
+
+        let expected: OwnedStruct = vec![
+            (text_token("a"), int(8)),
+            (
+                text_token("b"),
+                int(6).with_annotations(vec![text_token("x")]),
+            ),
+            (local_sid_token(21), OwnedValue::Boolean(true).into()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(expected, fields);
+
+        // removing the only field with a name leaves a struct equal to one that never had it
+        fields.remove_field("a");
+        fields.remove_field("b");
+        let expected: OwnedStruct = vec![(local_sid_token(21), OwnedElement::new_bool(true))]
+            .into_iter()
+            .collect();
+        assert_eq!(expected, fields);
+    }
+
+    #[test]
+    fn mutate_element() {
+        let mut element: OwnedElement =
+            OwnedStruct::from_iter(vec![("list", OwnedElement::new_list(vec![int(1)]))]).into();
+
+        element.set_annotations(vec![text_token("a"), text_token("b")]);
+        element.add_annotation("c");
+        assert_eq!(
+            vec!["a", "b", "c"],
+            element
+                .annotations()
+                .map(|a| a.text().unwrap())
+                .collect::<Vec<_>>()
+        );
+        element.clear_annotations();
+        assert_eq!(0, element.annotations().count());
+
+        let structure = element.as_struct_mut().unwrap();
+        structure
+            .get_mut("list")
+            .unwrap()
+            .as_sequence_mut()
+            .unwrap()
+            .push(int(2));
+        assert!(int(1).as_sequence_mut().is_none());
+        assert!(int(1).as_struct_mut().is_none());
+
+        let expected: OwnedElement =
+            OwnedStruct::from_iter(vec![("list", OwnedElement::new_list(vec![int(1), int(2)]))])
+                .into();
+        assert_eq!(expected, element);
+
+        *element.value_mut() = OwnedValue::String("replaced".into());
+        assert_eq!(&OwnedValue::String("replaced".into()), element.value());
+    }
 }
@@ -0,0 +1,418 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Provides a serde [`Serializer`](ser::Serializer) that builds [`OwnedElement`] trees, mapping
+//! Rust types onto Ion the same way as [`crate::ser`].
+
+use std::iter::once;
+
+use num_bigint::BigInt;
+use serde::ser::{self, Serialize};
+
+use crate::de::parse_ion_text;
+use crate::result::{illegal_operation, IonError, IonResult};
+use crate::ser::{TextSerializer, DECIMAL_NEWTYPE_NAME, TIMESTAMP_NEWTYPE_NAME};
+use crate::value::owned::{text_token, OwnedElement, OwnedValue};
+use crate::value::{Builder, Element};
+use crate::{IonType, Reader};
+
+/// Serializes `value` as an [`OwnedElement`].
+///
+/// ```
+/// # use ion_rs::result::IonResult;
+/// # use ion_rs::value::{to_element, Element, Struct};
+/// # fn main() -> IonResult<()> {
+/// let mut config = std::collections::BTreeMap::new();
+/// config.insert("port", 8080);
+/// let element = to_element(&config)?;
+/// assert_eq!(Some(8080), element.as_struct().unwrap().get("port").unwrap().as_i64());
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_element<T>(value: &T) -> IonResult<OwnedElement>
+where
+    T: ?Sized + Serialize,
+{
+    value.serialize(ElementSerializer)
+}
+
+// Prepends an enum variant's name to the element's annotations.
+fn annotate(variant: &'static str, element: OwnedElement) -> OwnedElement {
+    let annotations: Vec<_> = once(text_token(variant))
+        .chain(element.annotations().cloned())
+        .collect();
+    element.with_annotations(annotations)
+}
+
+struct ElementSerializer;
+
+impl ser::Serializer for ElementSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = SerializeList;
+    type SerializeMap = SerializeStruct;
+    type SerializeStruct = SerializeStruct;
+    type SerializeStructVariant = SerializeStruct;
+
+    fn serialize_bool(self, v: bool) -> IonResult<OwnedElement> {
+        Ok(OwnedElement::new_bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> IonResult<OwnedElement> {
+        Ok(OwnedElement::new_i64(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> IonResult<OwnedElement> {
+        Ok(OwnedElement::new_big_int(BigInt::from(v)))
+    }
+
+    fn serialize_u8(self, v: u8) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u64(self, v: u64) -> IonResult<OwnedElement> {
+        if v <= i64::MAX as u64 {
+            return self.serialize_i64(v as i64);
+        }
+        Ok(OwnedElement::new_big_int(BigInt::from(v)))
+    }
+
+    fn serialize_u128(self, v: u128) -> IonResult<OwnedElement> {
+        Ok(OwnedElement::new_big_int(BigInt::from(v)))
+    }
+
+    fn serialize_f32(self, v: f32) -> IonResult<OwnedElement> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> IonResult<OwnedElement> {
+        Ok(OwnedElement::new_f64(v))
+    }
+
+    fn serialize_char(self, v: char) -> IonResult<OwnedElement> {
+        Ok(OwnedValue::String(v.to_string()).into())
+    }
+
+    fn serialize_str(self, v: &str) -> IonResult<OwnedElement> {
+        Ok(OwnedValue::String(v.to_string()).into())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> IonResult<OwnedElement> {
+        Ok(OwnedValue::Blob(v.to_vec()).into())
+    }
+
+    fn serialize_none(self) -> IonResult<OwnedElement> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> IonResult<OwnedElement>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> IonResult<OwnedElement> {
+        Ok(OwnedElement::new_null(IonType::Null))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> IonResult<OwnedElement> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> IonResult<OwnedElement> {
+        Ok(OwnedElement::new_symbol(text_token(variant)))
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> IonResult<OwnedElement>
+    where
+        T: ?Sized + Serialize,
+    {
+        match name {
+            TIMESTAMP_NEWTYPE_NAME => {
+                let text = value.serialize(TextSerializer)?;
+                let timestamp = parse_ion_text(&text, Reader::read_timestamp)?;
+                Ok(OwnedElement::new_timestamp(timestamp))
+            }
+            DECIMAL_NEWTYPE_NAME => {
+                let text = value.serialize(TextSerializer)?;
+                let decimal = parse_ion_text(&text, Reader::read_decimal)?;
+                Ok(OwnedElement::new_decimal(decimal))
+            }
+            _ => value.serialize(self),
+        }
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> IonResult<OwnedElement>
+    where
+        T: ?Sized + Serialize,
+    {
+        Ok(annotate(variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> IonResult<SerializeList> {
+        Ok(SerializeList::new(None, len.unwrap_or(0)))
+    }
+
+    fn serialize_tuple(self, len: usize) -> IonResult<SerializeList> {
+        Ok(SerializeList::new(None, len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> IonResult<SerializeList> {
+        Ok(SerializeList::new(None, len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> IonResult<SerializeList> {
+        Ok(SerializeList::new(Some(variant), len))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> IonResult<SerializeStruct> {
+        Ok(SerializeStruct::new(None, len.unwrap_or(0)))
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> IonResult<SerializeStruct> {
+        Ok(SerializeStruct::new(None, len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> IonResult<SerializeStruct> {
+        Ok(SerializeStruct::new(Some(variant), len))
+    }
+}
+
+/// Collects the elements of a list, annotating it with the enum variant's name (if any).
+struct SerializeList {
+    variant: Option<&'static str>,
+    elements: Vec<OwnedElement>,
+}
+
+impl SerializeList {
+    fn new(variant: Option<&'static str>, len: usize) -> Self {
+        SerializeList {
+            variant,
+            elements: Vec::with_capacity(len),
+        }
+    }
+
+    fn push<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.elements.push(value.serialize(ElementSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        let list = OwnedElement::new_list(self.elements);
+        match self.variant {
+            Some(variant) => Ok(annotate(variant, list)),
+            None => Ok(list),
+        }
+    }
+}
+
+impl ser::SerializeSeq for SerializeList {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        SerializeList::end(self)
+    }
+}
+
+impl ser::SerializeTuple for SerializeList {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        SerializeList::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeList {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        SerializeList::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeList {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        SerializeList::end(self)
+    }
+}
+
+/// Collects the fields of a struct, annotating it with the enum variant's name (if any).
+struct SerializeStruct {
+    variant: Option<&'static str>,
+    fields: Vec<(String, OwnedElement)>,
+    // The name of the map entry whose value will be serialized next.
+    key: Option<String>,
+}
+
+impl SerializeStruct {
+    fn new(variant: Option<&'static str>, len: usize) -> Self {
+        SerializeStruct {
+            variant,
+            fields: Vec::with_capacity(len),
+            key: None,
+        }
+    }
+
+    fn insert<T>(&mut self, name: String, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.fields
+            .push((name, value.serialize(ElementSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        let fields = self
+            .fields
+            .into_iter()
+            .map(|(name, value)| (text_token(name), value));
+        let structure = OwnedElement::new_struct(fields);
+        match self.variant {
+            Some(variant) => Ok(annotate(variant, structure)),
+            None => Ok(structure),
+        }
+    }
+}
+
+impl ser::SerializeMap for SerializeStruct {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.key = Some(key.serialize(TextSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        match self.key.take() {
+            Some(key) => self.insert(key, value),
+            None => illegal_operation("serialize_value was called before serialize_key"),
+        }
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        SerializeStruct::end(self)
+    }
+}
+
+impl ser::SerializeStruct for SerializeStruct {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.insert(key.to_string(), value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        SerializeStruct::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeStruct {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> IonResult<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.insert(key.to_string(), value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        SerializeStruct::end(self)
+    }
+}
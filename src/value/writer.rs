@@ -5,10 +5,14 @@
 
 use super::{AnyInt, Element, Sequence, Struct, SymbolToken};
 use crate::result::{illegal_operation, IonError, IonResult};
+use crate::value::native_writer::{
+    NativeBinaryElementWriter, NativeElementWriter, NativeTextElementWriter,
+};
 use crate::IonType;
 use ion_c_sys::writer::{IonCValueWriter, IonCWriter, IonCWriterHandle};
 use ion_c_sys::ION_WRITER_OPTIONS;
 use std::convert::TryInto;
+use std::io;
 
 pub use Format::*;
 pub use TextKind::*;
@@ -75,6 +79,11 @@ impl<'a> IonCSliceElementWriter<'a> {
             Binary => {
                 options.output_as_binary = 1;
             }
+            Json(_) => {
+                return illegal_operation(
+                    "JSON output is only supported by Format::element_writer_for",
+                )
+            }
         };
         let writer = IonCWriterHandle::new_buf(buf, &mut options)?;
         Ok(Self {
@@ -229,7 +238,9 @@ pub enum TextKind {
 pub enum Format {
     Text(TextKind),
     Binary,
-    // TODO a mode for Json(TextKind)
+    /// JSON, down-converted from Ion. Annotations are dropped and Ion types that JSON does not
+    /// support are converted; see [`TextWriter::json`](crate::text::writer::TextWriter::json).
+    Json(TextKind),
 }
 
 impl Format {
@@ -244,7 +255,34 @@ impl Format {
         IonCSliceElementWriter::new(slice, self)
     }
 
-    // TODO into files, cursors, or other such things
+    /// Creates a [`ElementWriter`] for the format that writes to any [`io::Write`] sink, such
+    /// as a file, a socket or a `Vec<u8>`. Unlike [`Format::element_writer_for_slice`], there is
+    /// no limit on the size of the output. The sink is returned by [`ElementWriter::finish`].
+    ///
+    /// ## Usage
+    /// ```
+    /// # use ion_rs::result::IonResult;
+    /// # use ion_rs::value::owned::{OwnedElement, OwnedValue};
+    /// # use ion_rs::value::writer::*;
+    /// # fn main() -> IonResult<()> {
+    /// let element: OwnedElement = OwnedValue::String("hello".into()).into();
+    /// let mut writer = Format::Text(TextKind::Compact).element_writer_for(Vec::new())?;
+    /// writer.write(&element)?;
+    /// let output: Vec<u8> = writer.finish()?;
+    /// assert_eq!(b"\"hello\"\n", output.as_slice());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn element_writer_for<W: io::Write>(self, output: W) -> IonResult<NativeElementWriter<W>> {
+        let writer = match self {
+            Text(Compact) => NativeElementWriter::Text(NativeTextElementWriter::new(output)),
+            Text(Pretty) => NativeElementWriter::Text(NativeTextElementWriter::pretty(output)),
+            Binary => NativeElementWriter::Binary(NativeBinaryElementWriter::new(output)),
+            Json(Compact) => NativeElementWriter::Text(NativeTextElementWriter::json(output)),
+            Json(Pretty) => NativeElementWriter::Text(NativeTextElementWriter::pretty_json(output)),
+        };
+        Ok(writer)
+    }
 }
 
 #[cfg(test)]
@@ -254,7 +292,9 @@ mod writer_tests {
     use crate::types::decimal::Decimal;
     use crate::types::timestamp::Timestamp;
     use crate::value::borrowed::BorrowedElement;
-    use crate::value::owned::OwnedElement;
+    use crate::value::native_reader::NativeElementReader;
+    use crate::value::owned::{OwnedElement, OwnedValue};
+    use crate::value::reader::ElementReader;
     use crate::value::Builder;
     use rstest::*;
     use std::str::from_utf8;
@@ -482,6 +522,66 @@ mod writer_tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::binary(Binary)]
+    #[case::text(Text(Compact))]
+    #[case::pretty(Text(Pretty))]
+    fn write_to_io(#[case] format: Format) -> IonResult<()> {
+        let elements: Vec<OwnedElement> = vec![
+            list_case::<OwnedElement>().element,
+            sexp_case::<OwnedElement>().element,
+            struct_case::<OwnedElement>().element,
+        ];
+        let mut writer = format.element_writer_for(Vec::new())?;
+        writer.write_all(&elements)?;
+        let output = writer.finish()?;
+        assert_eq!(elements, NativeElementReader.read_all(&output)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::binary(Binary)]
+    #[case::text(Text(Compact))]
+    fn write_large_value(#[case] format: Format) -> IonResult<()> {
+        const STRING_LEN: usize = 64 * 1024;
+        let element: OwnedElement = OwnedValue::String("x".repeat(STRING_LEN)).into();
+        let mut writer = format.element_writer_for(Vec::new())?;
+        writer.write(&element)?;
+        let output = writer.finish()?;
+        assert!(output.len() > STRING_LEN);
+        assert_eq!(element, NativeElementReader.read_one(&output)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::compact(
+        Json(Compact),
+        "[[1,2,3]]\n[\"name\",[\"a\",\"b\",\"c\"]]\n{\"name\":1}\n"
+    )]
+    #[case::pretty(
+        Json(Pretty),
+        "[\n  [\n    1,\n    2,\n    3\n  ]\n]\n[\n  \"name\",\n  [\n    \"a\",\n    \"b\",\n    \
+         \"c\"\n  ]\n]\n{\n  \"name\": 1\n}\n"
+    )]
+    fn write_json(#[case] format: Format, #[case] expected: &str) -> IonResult<()> {
+        let elements: Vec<OwnedElement> = vec![
+            list_case::<OwnedElement>().element,
+            sexp_case::<OwnedElement>().element,
+            struct_case::<OwnedElement>().element,
+        ];
+        let mut writer = format.element_writer_for(Vec::new())?;
+        writer.write_all(&elements)?;
+        let output = writer.finish()?;
+        assert_eq!(expected, to_utf8(&output));
+        Ok(())
+    }
+
+    #[test]
+    fn json_slice_writer_fails() {
+        let mut buf = vec![0u8; 1024];
+        assert!(Json(Compact).element_writer_for_slice(&mut buf).is_err());
+    }
+
     fn assert_write<E, F>(expected: &[u8], element: &E, make_writer: F) -> IonResult<()>
     where
         E: Element,
@@ -0,0 +1,175 @@
+use crate::cursor::RawSymbolToken;
+use crate::result::IonResult;
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
+use crate::types::IonType;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset};
+use num_bigint::BigInt;
+
+/**
+ * This trait captures the format-agnostic functionality needed to write a stream of Ion values.
+ * It is implemented by both the text and binary writers, and is object safe, so callers can
+ * choose the output format at runtime (for example, by holding a `Box<dyn IonWriter>`).
+ *
+ * Annotations, field names and symbol values are specified as [RawSymbolToken]s. Writers that
+ * cannot encode a given token will return an Err; for example, the
+ * [BinarySystemWriter](crate::binary::writer::BinarySystemWriter) does not manage a symbol
+ * table and so can only write symbol IDs.
+ */
+pub trait IonWriter {
+    /// Sets the annotations that will be applied to the next value that is written.
+    fn set_annotations(&mut self, annotations: &[RawSymbolToken]) -> IonResult<()>;
+
+    /// Sets the field name of the next value that is written. This must be called before each
+    /// value written inside of a struct.
+    fn set_field_name(&mut self, name: RawSymbolToken) -> IonResult<()>;
+
+    /// Writes an Ion null of the specified type.
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()>;
+
+    /// Writes an Ion boolean with the specified value.
+    fn write_bool(&mut self, value: bool) -> IonResult<()>;
+
+    /// Writes an Ion integer with the specified value.
+    fn write_i64(&mut self, value: i64) -> IonResult<()>;
+
+    /// Writes an Ion integer with the specified value, which may be too large to fit in an i64.
+    fn write_big_int(&mut self, value: &BigInt) -> IonResult<()>;
+
+    /// Writes an Ion float with the specified value.
+    fn write_f32(&mut self, value: f32) -> IonResult<()>;
+
+    /// Writes an Ion float with the specified value.
+    fn write_f64(&mut self, value: f64) -> IonResult<()>;
+
+    /// Writes an Ion decimal with the specified value.
+    fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()>;
+
+    /// Writes an Ion timestamp with the specified value.
+    fn write_datetime(&mut self, value: &DateTime<FixedOffset>) -> IonResult<()>;
+
+    /// Writes an Ion decimal with the specified value, preserving negative zero.
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()>;
+
+    /// Writes an Ion timestamp with the specified value, preserving its precision and offset.
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()>;
+
+    /// Writes an Ion symbol with the specified value.
+    fn write_symbol(&mut self, value: RawSymbolToken) -> IonResult<()>;
+
+    /// Writes an Ion string with the specified value.
+    fn write_string(&mut self, value: &str) -> IonResult<()>;
+
+    /// Writes an Ion clob with the specified value.
+    fn write_clob(&mut self, value: &[u8]) -> IonResult<()>;
+
+    /// Writes an Ion blob with the specified value.
+    fn write_blob(&mut self, value: &[u8]) -> IonResult<()>;
+
+    /// Begins a container (List, S-Expression, or Struct). If `ion_type` is not a container type,
+    /// returns Err.
+    fn step_in(&mut self, ion_type: IonType) -> IonResult<()>;
+
+    /// Ends the current container. If the writer is not positioned inside a container, returns
+    /// Err.
+    fn step_out(&mut self) -> IonResult<()>;
+
+    /// Writes any buffered data to the underlying sink. This can only be called when the writer
+    /// is at the top level.
+    fn flush(&mut self) -> IonResult<()>;
+}
+
+#[cfg(test)]
+mod writer_tests {
+    use super::*;
+    use crate::binary::binary_writer::BinaryWriter;
+    use crate::binary::writer::BinarySystemWriter;
+    use crate::cursor::RawSymbolToken::{SymbolId, Text};
+    use crate::text::writer::TextWriter;
+    use crate::value::native_reader::NativeElementReader;
+    use crate::value::reader::ElementReader;
+    use std::str::from_utf8;
+
+    // Writes the same values regardless of the output format.
+    fn write_values(writer: &mut dyn IonWriter) -> IonResult<()> {
+        // foo::{name: "x", tags: [a, b], ratio: 1.5e0, small: 0.25e0, flag: true, bytes: {{AQI=}}}
+        writer.set_annotations(&[Text("foo")])?;
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name(Text("name"))?;
+        writer.write_string("x")?;
+        writer.set_field_name(Text("tags"))?;
+        writer.step_in(IonType::List)?;
+        writer.write_symbol(Text("a"))?;
+        writer.write_symbol(Text("b"))?;
+        writer.step_out()?;
+        writer.set_field_name(Text("ratio"))?;
+        writer.write_f64(1.5)?;
+        writer.set_field_name(Text("small"))?;
+        writer.write_f32(0.25)?;
+        writer.set_field_name(Text("flag"))?;
+        writer.write_bool(true)?;
+        writer.set_field_name(Text("bytes"))?;
+        writer.write_blob(&[1, 2])?;
+        writer.step_out()?;
+        writer.write_null(IonType::Integer)?;
+        writer.write_i64(-3)?;
+        writer.flush()
+    }
+
+    #[test]
+    fn text_and_binary_are_equivalent() -> IonResult<()> {
+        let mut text_writer = TextWriter::new(vec![]);
+        write_values(&mut text_writer)?;
+        let text = text_writer.into_output()?;
+
+        let mut binary_writer = BinaryWriter::new(vec![]);
+        write_values(&mut binary_writer)?;
+        let binary = binary_writer.into_output();
+
+        let text_elements = NativeElementReader.read_all(&text)?;
+        assert_eq!(text_elements.len(), 3);
+        assert_eq!(text_elements, NativeElementReader.read_all(&binary)?);
+        Ok(())
+    }
+
+    #[test]
+    fn choose_format_at_runtime() -> IonResult<()> {
+        for is_binary in &[true, false] {
+            let mut writer: Box<dyn IonWriter> = if *is_binary {
+                Box::new(BinaryWriter::new(vec![]))
+            } else {
+                Box::new(TextWriter::new(vec![]))
+            };
+            write_values(writer.as_mut())?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn text_symbol_ids() -> IonResult<()> {
+        let mut writer = TextWriter::new(vec![]);
+        IonWriter::set_annotations(&mut writer, &[SymbolId(10)])?;
+        writer.step_in(IonType::Struct)?;
+        IonWriter::set_field_name(&mut writer, SymbolId(11))?;
+        IonWriter::write_symbol(&mut writer, SymbolId(12))?;
+        writer.step_out()?;
+        let output = writer.into_output()?;
+        assert_eq!("$10::{$11:$12,}\n", from_utf8(&output).unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn binary_system_writer_requires_symbol_ids() -> IonResult<()> {
+        let mut writer = BinarySystemWriter::new(vec![]);
+        assert!(writer.set_annotations(&[Text("foo")]).is_err());
+        assert!(IonWriter::write_symbol(&mut writer, Text("foo")).is_err());
+        IonWriter::write_symbol(&mut writer, SymbolId(4))?;
+        writer.step_in(IonType::Struct)?;
+        assert!(IonWriter::set_field_name(&mut writer, Text("foo")).is_err());
+        IonWriter::set_field_name(&mut writer, SymbolId(4))?;
+        writer.write_i64(1)?;
+        writer.step_out()?;
+        IonWriter::flush(&mut writer)
+    }
+}
@@ -1,10 +1,13 @@
 // Copyright Amazon.com, Inc. or its affiliates.
 
 use ion_rs::result::{decoding_error, IonError, IonResult};
+use ion_rs::value::native_reader::NativeElementReader;
+use ion_rs::value::native_writer::{NativeBinaryElementWriter, NativeTextElementWriter};
 use ion_rs::value::owned::OwnedElement;
 use ion_rs::value::reader::{element_reader, ElementReader};
 use ion_rs::value::writer::{ElementWriter, Format, SliceElementWriter, TextKind};
 use ion_rs::value::{Element, Sequence, SymbolToken};
+use ion_rs::IonEq;
 use pretty_hex::*;
 use std::fs::read;
 use std::path::MAIN_SEPARATOR as PATH_SEPARATOR;
@@ -26,25 +29,32 @@ const ALL_SKIP_LIST: &[&str] = &[
     // these appear to have a problem specific to how we're calling ion-c (amzn/ion-rust#218)
     "ion-tests/iontestdata/good/item1.10n",
     "ion-tests/iontestdata/good/subfieldVarInt.ion",
+    // these are symbols with unknown text (amzn/ion-rust#219)
+    "ion-tests/iontestdata/good/typecodes/T7-small.10n",
+];
+
+/// Files that the Ion C backed reader and writers do not yet support. These are skipped (in
+/// addition to [`ALL_SKIP_LIST`]) by every test that reads or writes using Ion C.
+const ION_C_SKIP_LIST: &[&str] = &[
     // these are symbols with unknown text (amzn/ion-rust#219)
     "ion-tests/iontestdata/good/symbolExplicitZero.10n",
     "ion-tests/iontestdata/good/symbolImplicitZero.10n",
     "ion-tests/iontestdata/good/symbolZero.ion",
-    "ion-tests/iontestdata/good/typecodes/T7-small.10n",
 ];
 
+/// Files that the native reader does not yet support. These are skipped (in addition to
+/// [`ALL_SKIP_LIST`]) by every test that uses the [`NativeElementReader`].
+const NATIVE_SKIP_LIST: &[&str] = &[];
+
+/// Files that the native element writers do not yet support. These are skipped (in addition to
+/// [`ALL_SKIP_LIST`] and [`NATIVE_SKIP_LIST`]) by every test that uses a native writer.
+const NATIVE_WRITER_SKIP_LIST: &[&str] = &[];
+
 /// Files that should not be tested for equivalence with read_one against read_all
-const READ_ONE_EQUIVS_SKIP_LIST: &[&str] = &[
-    // we need a structural equality (IonEq) for these (amzn/ion-rust#220)
-    "ion-tests/iontestdata/good/floatSpecials.ion",
-];
+const READ_ONE_EQUIVS_SKIP_LIST: &[&str] = &[];
 
 /// Files that should not be tested for equivalence in round-trip testing
 const ROUND_TRIP_SKIP_LIST: &[&str] = &[
-    // we need a structural equality (IonEq) for these (amzn/ion-rust#220)
-    "ion-tests/iontestdata/good/float32.10n",
-    "ion-tests/iontestdata/good/floatSpecials.ion",
-    "ion-tests/iontestdata/good/non-equivs/floats.ion",
     // appears to be a bug with Ion C or ion-c-sys (specifically binary) (amzn/ion-rust#235)
     "ion-tests/iontestdata/good/equivs/bigInts.ion",
     "ion-tests/iontestdata/good/subfieldUInt.ion",
@@ -72,6 +82,16 @@ fn concat<'a>(left: &[&'a str], right: &[&'a str]) -> Vec<&'a str> {
         .collect()
 }
 
+/// Returns true if both slices have the same length and their elements are pairwise equivalent
+/// according to [`IonEq`].
+fn ion_eq_all(this: &[OwnedElement], that: &[OwnedElement]) -> bool {
+    this.len() == that.len()
+        && this
+            .iter()
+            .zip(that.iter())
+            .all(|(this_elem, that_elem)| this_elem.ion_eq(that_elem))
+}
+
 /// Determines if the given file name is in the paths list.  This deals with platform
 /// path separator differences from '/' separators in the path list.
 #[inline]
@@ -98,7 +118,12 @@ fn read_file<R: ElementReader>(reader: &R, file_name: &str) -> IonResult<Vec<Own
                     Ok(elem) => {
                         // only compare if we know equality to work
                         if !contains_path(READ_ONE_EQUIVS_SKIP_LIST, file_name) {
-                            assert_eq!(elems[0], elem)
+                            assert!(
+                                elems[0].ion_eq(&elem),
+                                "{:?} is not equivalent to {:?}",
+                                elems[0],
+                                elem
+                            )
                         }
                     }
                     Err(e) => panic!("Expected element {:?}, got {:?}", elems, e),
@@ -129,41 +154,106 @@ fn read_file<R: ElementReader>(reader: &R, file_name: &str) -> IonResult<Vec<Own
 }
 
 /// Asserts the given elements can be round-tripped and equivalent, then returns the new elements.
-fn assert_round_trip<F>(
+fn assert_round_trip<R, F>(
+    reader: &R,
     source_elements: &Vec<OwnedElement>,
     make_writer: F,
 ) -> IonResult<Vec<OwnedElement>>
 where
+    R: ElementReader,
     F: FnOnce(&mut [u8]) -> IonResult<SliceElementWriter>,
 {
     let mut buf = vec![0u8; WRITE_BUF_LENGTH];
     let mut writer = make_writer(&mut buf)?;
     writer.write_all(source_elements)?;
     let output = writer.finish()?;
-    let new_elements = element_reader().read_all(output)?;
-    assert_eq!(*source_elements, new_elements, "{:?}", output.hex_dump());
+    let new_elements = reader.read_all(output)?;
+    assert!(
+        ion_eq_all(source_elements, &new_elements),
+        "{:?} is not equivalent to {:?}\n{:?}",
+        source_elements,
+        new_elements,
+        output.hex_dump()
+    );
     Ok(new_elements)
 }
 
-fn assert_three_way_round_trip<F1, F2>(
+fn assert_three_way_round_trip<R, F1, F2>(
+    reader: &R,
     file_name: &str,
     first_writer: F1,
     second_writer: F2,
 ) -> IonResult<()>
 where
+    R: ElementReader,
     F1: FnOnce(&mut [u8]) -> IonResult<SliceElementWriter>,
     F2: FnOnce(&mut [u8]) -> IonResult<SliceElementWriter>,
 {
-    let source_elements = read_file(&element_reader(), file_name)?;
+    let source_elements = read_file(reader, file_name)?;
     if contains_path(ROUND_TRIP_SKIP_LIST, file_name) {
         return Ok(());
     }
-    let first_write_elements = assert_round_trip(&source_elements, first_writer)?;
-    let second_write_elements = assert_round_trip(&first_write_elements, second_writer)?;
-    assert_eq!(source_elements, second_write_elements);
+    let first_write_elements = assert_round_trip(reader, &source_elements, first_writer)?;
+    let second_write_elements = assert_round_trip(reader, &first_write_elements, second_writer)?;
+    assert!(
+        ion_eq_all(&source_elements, &second_write_elements),
+        "{:?} is not equivalent to {:?}",
+        source_elements,
+        second_write_elements
+    );
     Ok(())
 }
 
+/// Like [`assert_three_way_round_trip`], but for writers that write into a `Vec<u8>`.
+fn assert_native_three_way_round_trip<R, W1, W2>(
+    reader: &R,
+    file_name: &str,
+    first_writer: W1,
+    second_writer: W2,
+) -> IonResult<()>
+where
+    R: ElementReader,
+    W1: ElementWriter<Output = Vec<u8>>,
+    W2: ElementWriter<Output = Vec<u8>>,
+{
+    let source_elements = read_file(reader, file_name)?;
+    if contains_path(ROUND_TRIP_SKIP_LIST, file_name) {
+        return Ok(());
+    }
+    let first_write_elements = assert_native_round_trip(reader, &source_elements, first_writer)?;
+    let second_write_elements =
+        assert_native_round_trip(reader, &first_write_elements, second_writer)?;
+    assert!(
+        ion_eq_all(&source_elements, &second_write_elements),
+        "{:?} is not equivalent to {:?}",
+        source_elements,
+        second_write_elements
+    );
+    Ok(())
+}
+
+fn assert_native_round_trip<R, W>(
+    reader: &R,
+    source_elements: &Vec<OwnedElement>,
+    mut writer: W,
+) -> IonResult<Vec<OwnedElement>>
+where
+    R: ElementReader,
+    W: ElementWriter<Output = Vec<u8>>,
+{
+    writer.write_all(source_elements)?;
+    let output = writer.finish()?;
+    let new_elements = reader.read_all(&output)?;
+    assert!(
+        ion_eq_all(source_elements, &new_elements),
+        "{:?} is not equivalent to {:?}\n{:?}",
+        source_elements,
+        new_elements,
+        output.hex_dump()
+    );
+    Ok(new_elements)
+}
+
 fn assert_file<T, F: FnOnce() -> IonResult<T>>(skip_list: &[&str], file_name: &str, asserter: F) {
     // TODO if frehberg/test-generator#7 gets implemented we could do a proper ignore
 
@@ -180,8 +270,10 @@ fn assert_file<T, F: FnOnce() -> IonResult<T>>(skip_list: &[&str], file_name: &s
 #[test_resources("ion-tests/iontestdata/good/**/*.ion")]
 #[test_resources("ion-tests/iontestdata/good/**/*.10n")]
 fn good_roundtrip_text_binary(file_name: &str) {
-    assert_file(ALL_SKIP_LIST, file_name, || {
+    let skip_list = concat(ALL_SKIP_LIST, ION_C_SKIP_LIST);
+    assert_file(&skip_list[..], file_name, || {
         assert_three_way_round_trip(
+            &element_reader(),
             file_name,
             |slice| Format::Text(TextKind::Compact).element_writer_for_slice(slice),
             |slice| Format::Binary.element_writer_for_slice(slice),
@@ -192,8 +284,10 @@ fn good_roundtrip_text_binary(file_name: &str) {
 #[test_resources("ion-tests/iontestdata/good/**/*.ion")]
 #[test_resources("ion-tests/iontestdata/good/**/*.10n")]
 fn good_roundtrip_binary_text(file_name: &str) {
-    assert_file(ALL_SKIP_LIST, file_name, || {
+    let skip_list = concat(ALL_SKIP_LIST, ION_C_SKIP_LIST);
+    assert_file(&skip_list[..], file_name, || {
         assert_three_way_round_trip(
+            &element_reader(),
             file_name,
             |slice| Format::Binary.element_writer_for_slice(slice),
             |slice| Format::Text(TextKind::Compact).element_writer_for_slice(slice),
@@ -204,8 +298,10 @@ fn good_roundtrip_binary_text(file_name: &str) {
 #[test_resources("ion-tests/iontestdata/good/**/*.ion")]
 #[test_resources("ion-tests/iontestdata/good/**/*.10n")]
 fn good_roundtrip_text_pretty(file_name: &str) {
-    assert_file(ALL_SKIP_LIST, file_name, || {
+    let skip_list = concat(ALL_SKIP_LIST, ION_C_SKIP_LIST);
+    assert_file(&skip_list[..], file_name, || {
         assert_three_way_round_trip(
+            &element_reader(),
             file_name,
             |slice| Format::Text(TextKind::Compact).element_writer_for_slice(slice),
             |slice| Format::Text(TextKind::Pretty).element_writer_for_slice(slice),
@@ -216,8 +312,10 @@ fn good_roundtrip_text_pretty(file_name: &str) {
 #[test_resources("ion-tests/iontestdata/good/**/*.ion")]
 #[test_resources("ion-tests/iontestdata/good/**/*.10n")]
 fn good_roundtrip_pretty_text(file_name: &str) {
-    assert_file(ALL_SKIP_LIST, file_name, || {
+    let skip_list = concat(ALL_SKIP_LIST, ION_C_SKIP_LIST);
+    assert_file(&skip_list[..], file_name, || {
         assert_three_way_round_trip(
+            &element_reader(),
             file_name,
             |slice| Format::Text(TextKind::Pretty).element_writer_for_slice(slice),
             |slice| Format::Text(TextKind::Compact).element_writer_for_slice(slice),
@@ -228,8 +326,10 @@ fn good_roundtrip_pretty_text(file_name: &str) {
 #[test_resources("ion-tests/iontestdata/good/**/*.ion")]
 #[test_resources("ion-tests/iontestdata/good/**/*.10n")]
 fn good_roundtrip_pretty_binary(file_name: &str) {
-    assert_file(ALL_SKIP_LIST, file_name, || {
+    let skip_list = concat(ALL_SKIP_LIST, ION_C_SKIP_LIST);
+    assert_file(&skip_list[..], file_name, || {
         assert_three_way_round_trip(
+            &element_reader(),
             file_name,
             |slice| Format::Text(TextKind::Pretty).element_writer_for_slice(slice),
             |slice| Format::Binary.element_writer_for_slice(slice),
@@ -240,8 +340,10 @@ fn good_roundtrip_pretty_binary(file_name: &str) {
 #[test_resources("ion-tests/iontestdata/good/**/*.ion")]
 #[test_resources("ion-tests/iontestdata/good/**/*.10n")]
 fn good_roundtrip_binary_pretty(file_name: &str) {
-    assert_file(ALL_SKIP_LIST, file_name, || {
+    let skip_list = concat(ALL_SKIP_LIST, ION_C_SKIP_LIST);
+    assert_file(&skip_list[..], file_name, || {
         assert_three_way_round_trip(
+            &element_reader(),
             file_name,
             |slice| Format::Binary.element_writer_for_slice(slice),
             |slice| Format::Text(TextKind::Pretty).element_writer_for_slice(slice),
@@ -252,8 +354,13 @@ fn good_roundtrip_binary_pretty(file_name: &str) {
 #[test_resources("ion-tests/iontestdata/bad/**/*.ion")]
 #[test_resources("ion-tests/iontestdata/bad/**/*.10n")]
 fn bad(file_name: &str) {
-    assert_file(ALL_SKIP_LIST, file_name, || {
-        match read_file(&element_reader(), file_name) {
+    let skip_list = concat(ALL_SKIP_LIST, ION_C_SKIP_LIST);
+    assert_bad(&element_reader(), &skip_list[..], file_name);
+}
+
+fn assert_bad<R: ElementReader>(reader: &R, skip_list: &[&str], file_name: &str) {
+    assert_file(skip_list, file_name, || {
+        match read_file(reader, file_name) {
             Ok(items) => panic!("Expected error, got: {:?}", items),
             Err(_) => Ok(()),
         }
@@ -352,13 +459,34 @@ where
 #[test_resources("ion-tests/iontestdata/good/equivs/**/*.ion")]
 #[test_resources("ion-tests/iontestdata/good/equivs/**/*.10n")]
 fn equivs(file_name: &str) {
-    let skip_list = concat(ALL_SKIP_LIST, EQUIVS_SKIP_LIST);
-    assert_file(&skip_list[..], file_name, || {
+    let skip_list = concat(
+        &concat(ALL_SKIP_LIST, EQUIVS_SKIP_LIST)[..],
+        ION_C_SKIP_LIST,
+    );
+    assert_equivs(element_reader(), &skip_list[..], file_name);
+}
+
+fn assert_equivs<R: ElementReader>(reader: R, skip_list: &[&str], file_name: &str) {
+    assert_file(skip_list, file_name, || {
         read_group(
-            element_reader(),
+            reader,
             file_name,
-            |this, that| assert_eq!(this, that),
-            |this_group, that_group| assert_eq!(this_group, that_group),
+            |this, that| {
+                assert!(
+                    this.ion_eq(that),
+                    "{:?} is not equivalent to {:?}",
+                    this,
+                    that
+                )
+            },
+            |this_group, that_group| {
+                assert!(
+                    ion_eq_all(this_group, that_group),
+                    "{:?} is not equivalent to {:?}",
+                    this_group,
+                    that_group
+                )
+            },
         )
     });
 }
@@ -368,25 +496,173 @@ fn equivs(file_name: &str) {
 // see frehberg/test-generator#12
 //#[test_resources("ion-tests/iontestdata/good/non-equivs/**/*.10n")]
 fn non_equivs(file_name: &str) {
-    let skip_list = concat(ALL_SKIP_LIST, NON_EQUIVS_SKIP_LIST);
-    assert_file(&skip_list[..], file_name, || {
+    let skip_list = concat(
+        &concat(ALL_SKIP_LIST, NON_EQUIVS_SKIP_LIST)[..],
+        ION_C_SKIP_LIST,
+    );
+    assert_non_equivs(element_reader(), &skip_list[..], file_name);
+}
+
+fn assert_non_equivs<R: ElementReader>(reader: R, skip_list: &[&str], file_name: &str) {
+    assert_file(skip_list, file_name, || {
         read_group(
-            element_reader(),
+            reader,
             file_name,
             |this, that| {
                 if std::ptr::eq(this, that) {
-                    assert_eq!(this, that);
+                    assert!(this.ion_eq(that), "{:?} is not equivalent to itself", this);
                 } else {
-                    assert_ne!(this, that);
+                    assert!(!this.ion_eq(that), "{:?} is equivalent to {:?}", this, that);
                 }
             },
             |this_group, that_group| {
                 if std::ptr::eq(this_group, that_group) {
-                    assert_eq!(this_group, that_group);
+                    assert!(
+                        ion_eq_all(this_group, that_group),
+                        "{:?} is not equivalent to itself",
+                        this_group
+                    );
                 } else {
-                    assert_ne!(this_group, that_group);
+                    assert!(
+                        !ion_eq_all(this_group, that_group),
+                        "{:?} is equivalent to {:?}",
+                        this_group,
+                        that_group
+                    );
                 }
             },
         )
     });
 }
+
+// The tests below run the same suites using the NativeElementReader.
+
+#[test_resources("ion-tests/iontestdata/good/**/*.ion")]
+#[test_resources("ion-tests/iontestdata/good/**/*.10n")]
+fn native_good_roundtrip_text_binary(file_name: &str) {
+    let skip_list = concat(
+        &concat(ALL_SKIP_LIST, NATIVE_SKIP_LIST)[..],
+        ION_C_SKIP_LIST,
+    );
+    assert_file(&skip_list[..], file_name, || {
+        assert_three_way_round_trip(
+            &NativeElementReader,
+            file_name,
+            |slice| Format::Text(TextKind::Compact).element_writer_for_slice(slice),
+            |slice| Format::Binary.element_writer_for_slice(slice),
+        )
+    });
+}
+
+#[test_resources("ion-tests/iontestdata/good/**/*.ion")]
+#[test_resources("ion-tests/iontestdata/good/**/*.10n")]
+fn native_good_roundtrip_binary_text(file_name: &str) {
+    let skip_list = concat(
+        &concat(ALL_SKIP_LIST, NATIVE_SKIP_LIST)[..],
+        ION_C_SKIP_LIST,
+    );
+    assert_file(&skip_list[..], file_name, || {
+        assert_three_way_round_trip(
+            &NativeElementReader,
+            file_name,
+            |slice| Format::Binary.element_writer_for_slice(slice),
+            |slice| Format::Text(TextKind::Compact).element_writer_for_slice(slice),
+        )
+    });
+}
+
+#[test_resources("ion-tests/iontestdata/good/**/*.ion")]
+#[test_resources("ion-tests/iontestdata/good/**/*.10n")]
+fn native_good_roundtrip_pretty_binary(file_name: &str) {
+    let skip_list = concat(
+        &concat(ALL_SKIP_LIST, NATIVE_SKIP_LIST)[..],
+        ION_C_SKIP_LIST,
+    );
+    assert_file(&skip_list[..], file_name, || {
+        assert_three_way_round_trip(
+            &NativeElementReader,
+            file_name,
+            |slice| Format::Text(TextKind::Pretty).element_writer_for_slice(slice),
+            |slice| Format::Binary.element_writer_for_slice(slice),
+        )
+    });
+}
+
+#[test_resources("ion-tests/iontestdata/bad/**/*.ion")]
+#[test_resources("ion-tests/iontestdata/bad/**/*.10n")]
+fn native_bad(file_name: &str) {
+    let skip_list = concat(ALL_SKIP_LIST, NATIVE_SKIP_LIST);
+    assert_bad(&NativeElementReader, &skip_list[..], file_name);
+}
+
+#[test_resources("ion-tests/iontestdata/good/equivs/**/*.ion")]
+#[test_resources("ion-tests/iontestdata/good/equivs/**/*.10n")]
+fn native_equivs(file_name: &str) {
+    let skip_list = concat(
+        &concat(ALL_SKIP_LIST, EQUIVS_SKIP_LIST)[..],
+        NATIVE_SKIP_LIST,
+    );
+    assert_equivs(NativeElementReader, &skip_list[..], file_name);
+}
+
+#[test_resources("ion-tests/iontestdata/good/non-equivs/**/*.ion")]
+fn native_non_equivs(file_name: &str) {
+    let skip_list = concat(
+        &concat(ALL_SKIP_LIST, NON_EQUIVS_SKIP_LIST)[..],
+        NATIVE_SKIP_LIST,
+    );
+    assert_non_equivs(NativeElementReader, &skip_list[..], file_name);
+}
+
+// The tests below write using the NativeBinaryElementWriter and the NativeTextElementWriter.
+
+#[test_resources("ion-tests/iontestdata/good/**/*.ion")]
+#[test_resources("ion-tests/iontestdata/good/**/*.10n")]
+fn native_writer_good_roundtrip_binary(file_name: &str) {
+    let skip_list = concat(
+        &concat(ALL_SKIP_LIST, NATIVE_SKIP_LIST)[..],
+        NATIVE_WRITER_SKIP_LIST,
+    );
+    assert_file(&skip_list[..], file_name, || {
+        assert_native_three_way_round_trip(
+            &NativeElementReader,
+            file_name,
+            NativeBinaryElementWriter::new(vec![]),
+            NativeBinaryElementWriter::new(vec![]),
+        )
+    });
+}
+
+#[test_resources("ion-tests/iontestdata/good/**/*.ion")]
+#[test_resources("ion-tests/iontestdata/good/**/*.10n")]
+fn native_writer_good_roundtrip_text_binary(file_name: &str) {
+    let skip_list = concat(
+        &concat(ALL_SKIP_LIST, NATIVE_SKIP_LIST)[..],
+        NATIVE_WRITER_SKIP_LIST,
+    );
+    assert_file(&skip_list[..], file_name, || {
+        assert_native_three_way_round_trip(
+            &NativeElementReader,
+            file_name,
+            NativeTextElementWriter::new(vec![]),
+            NativeBinaryElementWriter::new(vec![]),
+        )
+    });
+}
+
+#[test_resources("ion-tests/iontestdata/good/**/*.ion")]
+#[test_resources("ion-tests/iontestdata/good/**/*.10n")]
+fn native_writer_good_roundtrip_pretty_text(file_name: &str) {
+    let skip_list = concat(
+        &concat(ALL_SKIP_LIST, NATIVE_SKIP_LIST)[..],
+        NATIVE_WRITER_SKIP_LIST,
+    );
+    assert_file(&skip_list[..], file_name, || {
+        assert_native_three_way_round_trip(
+            &NativeElementReader,
+            file_name,
+            NativeTextElementWriter::pretty(vec![]),
+            NativeTextElementWriter::new(vec![]),
+        )
+    });
+}